@@ -14,7 +14,15 @@ use crate::skills_runner::{
 use crate::skills_runtime::{validate_version_override, SkillsRuntimeManager, SkillsRuntimeStatus};
 use serde::Serialize;
 use std::path::{Path, PathBuf};
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long a list read waits for a running command before reporting that
+/// the runner is busy. Commands may run for up to the runner timeout, and a
+/// refresh should not freeze for that long.
+const READ_LOCK_WAIT: Duration = Duration::from_secs(2);
+const READ_LOCK_POLL_INTERVAL: Duration = Duration::from_millis(25);
 
 #[derive(Debug, Clone, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
@@ -28,6 +36,29 @@ pub struct AppContext {
     pub project_agents_toml_path: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project_initialized: Option<bool>,
+    /// `true` when `agents.toml` exists but cannot be opened for writing
+    /// (read-only mount or permissions), so write commands are refused.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_read_only: Option<bool>,
+    /// Set when the project folder lives inside a cloud-synced folder, where
+    /// the symlinks dotagents creates are known to get mangled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_cloud_sync_provider: Option<String>,
+}
+
+/// What a folder already contains before it is picked as the project root.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectRootInspection {
+    pub project_root: String,
+    pub has_agents_toml: bool,
+    pub has_agents_lock: bool,
+    pub has_agents_dir: bool,
+    pub has_skills_lock: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud_sync_provider: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, PartialEq, Eq)]
@@ -45,8 +76,8 @@ pub struct AppState {
     runner: DotagentsRunner,
     skills_runner: Arc<Mutex<SkillsRunner>>,
     home_dir: PathBuf,
-    command_lock: Arc<Mutex<()>>,
-    skills_command_lock: Arc<Mutex<()>>,
+    command_lock: Arc<RwLock<()>>,
+    skills_command_lock: Arc<RwLock<()>>,
 }
 
 impl AppState {
@@ -62,8 +93,8 @@ impl AppState {
             runner,
             skills_runner: Arc::new(Mutex::new(skills_runner)),
             home_dir,
-            command_lock: Arc::new(Mutex::new(())),
-            skills_command_lock: Arc::new(Mutex::new(())),
+            command_lock: Arc::new(RwLock::new(())),
+            skills_command_lock: Arc::new(RwLock::new(())),
         }
     }
 
@@ -94,10 +125,31 @@ impl AppState {
         Ok(self.build_app_context(&settings))
     }
 
+    /// Describe a candidate project folder without selecting it or touching
+    /// settings, so the picker can preview what is already there.
+    pub fn inspect_project_root(
+        &self,
+        project_root: &str,
+    ) -> Result<ProjectRootInspection, String> {
+        let root = normalize_project_root(project_root)?;
+        let agents_toml = existing_agents_toml(&root);
+        Ok(ProjectRootInspection {
+            project_root: root.display().to_string(),
+            has_agents_toml: agents_toml.is_some(),
+            has_agents_lock: root.join("agents.lock").is_file(),
+            has_agents_dir: root.join(".agents").is_dir(),
+            has_skills_lock: root.join("skills-lock.json").is_file(),
+            read_only: agents_toml.map(|agents_toml| check_writable(&agents_toml).is_err()),
+            cloud_sync_provider: detect_cloud_sync_provider(&root, &self.home_dir)
+                .map(str::to_string),
+        })
+    }
+
     pub fn list_skills(&self) -> Result<Vec<DotagentsSkillListItem>, String> {
         let Some(context) = self.active_execution_context_for_read()? else {
             return Ok(Vec::new());
         };
+        let _guard = wait_for_read_lock(&self.command_lock, "dotagents")?;
         self.runner.list_skills(&context)
     }
 
@@ -105,6 +157,7 @@ impl AppState {
         let Some(context) = self.active_execution_context_for_read()? else {
             return Ok(Vec::new());
         };
+        let _guard = wait_for_read_lock(&self.command_lock, "dotagents")?;
         self.runner.list_mcp_servers(&context)
     }
 
@@ -122,10 +175,7 @@ impl AppState {
                     .preflight_failure_result(&request, &fallback_context, error)
             }
         };
-        let _guard = self
-            .command_lock
-            .lock()
-            .map_err(|e| format!("failed to lock dotagents command runner: {e}"))?;
+        let _guard = self.lock_dotagents_commands()?;
         self.runner.run_command(&context, &request)
     }
 
@@ -235,6 +285,7 @@ impl AppState {
 
     pub fn list_skills_cli(&self) -> Result<Vec<SkillsCliListItem>, String> {
         let context = self.skills_execution_context()?;
+        let _guard = wait_for_read_lock(&self.skills_command_lock, "skills")?;
         self.with_skills_runner(|r| r.list_skills(&context))?
     }
 
@@ -243,13 +294,28 @@ impl AppState {
         request: SkillsCliCommandRequest,
     ) -> Result<SkillsCliCommandResult, String> {
         let context = self.skills_execution_context()?;
-        let _guard = self
-            .skills_command_lock
-            .lock()
-            .map_err(|e| format!("failed to lock skills command runner: {e}"))?;
+        let _guard = self.lock_skills_commands()?;
         self.with_skills_runner(|r| r.run_command(&context, &request))?
     }
 
+    /// Dotagents commands hold this lock exclusively so a `list` never
+    /// observes `agents.toml` halfway through an `add`/`install` started
+    /// elsewhere. There is no CLI or watcher: every vendor invocation goes
+    /// through `AppState`, so this lock already orders all of them. Writes
+    /// queue here; reads share the lock through [`wait_for_read_lock`], so
+    /// they run alongside each other and only give up behind a write.
+    fn lock_dotagents_commands(&self) -> Result<RwLockWriteGuard<'_, ()>, String> {
+        self.command_lock
+            .write()
+            .map_err(|e| format!("failed to lock dotagents command runner: {e}"))
+    }
+
+    fn lock_skills_commands(&self) -> Result<RwLockWriteGuard<'_, ()>, String> {
+        self.skills_command_lock
+            .write()
+            .map_err(|e| format!("failed to lock skills command runner: {e}"))
+    }
+
     fn skills_execution_context(&self) -> Result<SkillsExecutionContext, String> {
         let settings = self.load_settings()?;
         let scope = settings.skills_workspace_state.scope;
@@ -269,11 +335,14 @@ impl AppState {
     where
         F: FnOnce(&SkillsRunner) -> R,
     {
-        let guard = self
+        // Run on a copy so a long command does not hold the runner mutex and
+        // block unrelated reads such as agent detection.
+        let runner = self
             .skills_runner
             .lock()
-            .map_err(|e| format!("failed to access skills runner: {e}"))?;
-        Ok(f(&guard))
+            .map_err(|e| format!("failed to access skills runner: {e}"))?
+            .clone();
+        Ok(f(&runner))
     }
 
     #[cfg(test)]
@@ -309,9 +378,15 @@ impl AppState {
         let project_agents_toml_path = project_root
             .as_ref()
             .map(|root| root.join("agents.toml").display().to_string());
-        let project_initialized = project_root
-            .as_ref()
-            .map(|root| root.join("agents.toml").exists());
+        let project_agents_toml = project_root.as_deref().map(existing_agents_toml);
+        let project_initialized = project_agents_toml.as_ref().map(Option::is_some);
+        let project_read_only = project_agents_toml
+            .flatten()
+            .map(|agents_toml| check_writable(&agents_toml).is_err());
+        let project_cloud_sync_provider = project_root
+            .as_deref()
+            .and_then(|root| detect_cloud_sync_provider(root, &self.home_dir))
+            .map(str::to_string);
 
         AppContext {
             active_project_context: settings.active_project_context.clone(),
@@ -321,6 +396,8 @@ impl AppState {
             user_initialized: self.user_agents_toml_path().exists(),
             project_agents_toml_path,
             project_initialized,
+            project_read_only,
+            project_cloud_sync_provider,
         }
     }
 
@@ -343,7 +420,7 @@ impl AppState {
                     return Ok(None);
                 };
                 let root_path = PathBuf::from(root);
-                if !root_path.join("agents.toml").exists() {
+                if existing_agents_toml(&root_path).is_none() {
                     return Ok(None);
                 }
                 Ok(Some(DotagentsExecutionContext {
@@ -380,12 +457,18 @@ impl AppState {
                     scope: DotagentsScope::Project,
                     cwd: PathBuf::from(root),
                 };
-                if context.cwd.join("agents.toml").exists() {
-                    return Ok(context);
-                }
-                Err(String::from(
-                    "The selected project folder does not contain agents.toml.",
-                ))
+                let Some(agents_toml) = existing_agents_toml(&context.cwd) else {
+                    return Err(String::from(
+                        "The selected project folder does not contain agents.toml.",
+                    ));
+                };
+                check_writable(&agents_toml).map_err(|error| {
+                    format!(
+                        "The selected project folder is read-only; dotagents cannot update {}: {error}",
+                        agents_toml.display()
+                    )
+                })?;
+                Ok(context)
             }
         }
     }
@@ -423,6 +506,31 @@ impl AppState {
     }
 }
 
+/// Share a command lock for a list read, waiting at most [`READ_LOCK_WAIT`]
+/// for a running command to finish. Other reads never block it.
+fn wait_for_read_lock<'a>(
+    lock: &'a RwLock<()>,
+    cli: &str,
+) -> Result<RwLockReadGuard<'a, ()>, String> {
+    let deadline = Instant::now() + READ_LOCK_WAIT;
+    loop {
+        match lock.try_read() {
+            Ok(guard) => return Ok(guard),
+            Err(TryLockError::Poisoned(e)) => {
+                return Err(format!("failed to lock {cli} command runner: {e}"))
+            }
+            Err(TryLockError::WouldBlock) if Instant::now() < deadline => {
+                thread::sleep(READ_LOCK_POLL_INTERVAL);
+            }
+            Err(TryLockError::WouldBlock) => {
+                return Err(format!(
+                    "Another {cli} command is still running. Refresh once it finishes."
+                ))
+            }
+        }
+    }
+}
+
 fn normalize_project_root(value: &str) -> Result<PathBuf, String> {
     let trimmed = value.trim();
     if trimmed.is_empty() {
@@ -444,13 +552,106 @@ fn normalize_project_root(value: &str) -> Result<PathBuf, String> {
         .map_err(|error| format!("failed to resolve {}: {error}", path.display()))
 }
 
+/// The project's `agents.toml`, if the folder has been initialized. Every
+/// project check goes through this so "initialized" means the same thing in
+/// the context, the write preflight, and folder inspection.
+fn existing_agents_toml(project_root: &Path) -> Option<PathBuf> {
+    Some(project_root.join("agents.toml")).filter(|agents_toml| agents_toml.is_file())
+}
+
+/// Probe write access without writing. A file with no write permission bits
+/// counts as read-only even for users that could bypass them (root), then the
+/// file is opened for appending so read-only mounts (`EROFS`) and missing
+/// permissions (`EACCES`) are reported the same way a write would be.
+fn check_writable(path: &Path) -> std::io::Result<()> {
+    if std::fs::metadata(path)?.permissions().readonly() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            "file is marked read-only",
+        ));
+    }
+    std::fs::OpenOptions::new()
+        .append(true)
+        .open(path)
+        .map(|_| ())
+}
+
+/// Name the cloud sync client that manages `path`, if any. Provider folders
+/// are only recognized where the clients create them: under
+/// `~/Library/CloudStorage` or `~/Library/Mobile Documents` on macOS, or
+/// directly inside the home directory. Anywhere else only a `.dropbox`
+/// marker in an ancestor counts, so a checkout named `Dropbox-sdk-python` is
+/// not flagged.
+fn detect_cloud_sync_provider(path: &Path, home_dir: &Path) -> Option<&'static str> {
+    let components = path
+        .components()
+        .filter_map(|component| component.as_os_str().to_str())
+        .collect::<Vec<_>>();
+    if components
+        .windows(2)
+        .any(|pair| pair == ["Library", "Mobile Documents"])
+    {
+        return Some("iCloud Drive");
+    }
+    if let Some(name) = components.windows(3).find_map(|window| match window {
+        ["Library", "CloudStorage", folder] => cloud_storage_provider(folder),
+        _ => None,
+    }) {
+        return Some(name);
+    }
+    if let Some(name) = path
+        .strip_prefix(home_dir)
+        .ok()
+        .and_then(|relative| relative.components().next())
+        .and_then(|folder| folder.as_os_str().to_str())
+        .and_then(home_folder_provider)
+    {
+        return Some(name);
+    }
+    path.ancestors()
+        .any(|ancestor| ancestor.join(".dropbox").is_file())
+        .then_some("Dropbox")
+}
+
+/// Folder names the macOS File Provider clients use in `~/Library/CloudStorage`.
+fn cloud_storage_provider(name: &str) -> Option<&'static str> {
+    if name == "Dropbox" || name.starts_with("Dropbox-") {
+        Some("Dropbox")
+    } else if name.starts_with("OneDrive-") {
+        Some("OneDrive")
+    } else if name.starts_with("GoogleDrive-") {
+        Some("Google Drive")
+    } else {
+        None
+    }
+}
+
+/// Sync folders the desktop clients create directly inside the home directory.
+fn home_folder_provider(name: &str) -> Option<&'static str> {
+    if name == "Dropbox" || name.starts_with("Dropbox (") {
+        Some("Dropbox")
+    } else if name == "OneDrive" || name.starts_with("OneDrive - ") {
+        Some("OneDrive")
+    } else if name == "Google Drive" {
+        Some("Google Drive")
+    } else if name == "iCloudDrive" {
+        Some("iCloud Drive")
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::AppState;
-    use crate::dotagents_runner::DotagentsRunner;
+    use super::{check_writable, detect_cloud_sync_provider, wait_for_read_lock, AppState};
+    use crate::dotagents_runner::{DotagentsCommandRequest, DotagentsRunner};
     use crate::dotagents_runtime::DotagentsRuntimeManager;
     use crate::settings::DotagentsScope;
     use std::fs;
+    #[cfg(unix)]
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::Path;
+    use std::sync::RwLock;
     use tempfile::tempdir;
 
     #[test]
@@ -488,4 +689,167 @@ mod tests {
             project_root.canonicalize().expect("canonical project")
         );
     }
+
+    #[test]
+    fn detects_cloud_synced_project_roots() {
+        for (home, path, provider) in [
+            (
+                "/Users/me",
+                "/Users/me/Library/Mobile Documents/com~apple~CloudDocs/repo",
+                "iCloud Drive",
+            ),
+            (
+                "/Users/me",
+                "/Users/me/Library/CloudStorage/OneDrive-Contoso/repo",
+                "OneDrive",
+            ),
+            (
+                "/Users/me",
+                "/Users/me/Library/CloudStorage/GoogleDrive-me@example.com/My Drive/repo",
+                "Google Drive",
+            ),
+            ("/home/me", "/home/me/Dropbox/repo", "Dropbox"),
+            ("/home/me", "/home/me/Dropbox (Personal)/repo", "Dropbox"),
+            (
+                "C:/Users/me",
+                "C:/Users/me/OneDrive - Contoso/repo",
+                "OneDrive",
+            ),
+        ] {
+            assert_eq!(
+                detect_cloud_sync_provider(Path::new(path), Path::new(home)),
+                Some(provider),
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn ignores_provider_names_outside_sync_locations() {
+        for path in [
+            "/home/me/Dev/repo",
+            "/home/me/src/Dropbox-sdk-python",
+            "/home/me/src/Dropbox/repo",
+            "/home/me/src/OneDrive-tools",
+            "/home/me/Library/Mobile Documents-notes/repo",
+        ] {
+            assert_eq!(
+                detect_cloud_sync_provider(Path::new(path), Path::new("/home/me")),
+                None,
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn detects_dropbox_marker_in_ancestor() {
+        let temp = tempdir().expect("tempdir");
+        let sync_root = temp.path().join("work-sync");
+        let project_root = sync_root.join("repo");
+        fs::create_dir_all(&project_root).expect("project");
+        fs::write(sync_root.join(".dropbox"), "{}").expect("marker");
+
+        assert_eq!(
+            detect_cloud_sync_provider(&project_root, Path::new("/home/me")),
+            Some("Dropbox")
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn read_only_project_refuses_write_commands() {
+        let temp = tempdir().expect("tempdir");
+        let home_dir = temp.path().join("home");
+        let project_root = temp.path().join("project");
+        fs::create_dir_all(&home_dir).expect("home");
+        fs::create_dir_all(&project_root).expect("project");
+        let agents_toml = project_root.join("agents.toml");
+        fs::write(&agents_toml, "version = 1\nskills = []\n").expect("agents.toml");
+        fs::set_permissions(&agents_toml, fs::Permissions::from_mode(0o444)).expect("chmod");
+        assert!(check_writable(&agents_toml).is_err());
+
+        let state = AppState::new(
+            home_dir.clone(),
+            temp.path().join("settings"),
+            DotagentsRunner::new(home_dir, DotagentsRuntimeManager::new()),
+        );
+        let context = state
+            .set_project_root(Some(project_root.display().to_string()))
+            .expect("set project root");
+        assert_eq!(context.project_read_only, Some(true));
+
+        let result = state
+            .run_dotagents_command(DotagentsCommandRequest::Sync)
+            .expect("sync preflight");
+        assert!(!result.success);
+        assert!(result.stderr.contains("read-only"));
+    }
+
+    #[test]
+    fn inspects_project_root_without_selecting_it() {
+        let temp = tempdir().expect("tempdir");
+        let home = temp.path().join("home");
+        let project = temp.path().join("project");
+        fs::create_dir_all(project.join(".agents")).expect("create project");
+        fs::write(project.join("agents.toml"), "version = 1\n").expect("write agents.toml");
+        fs::write(project.join("skills-lock.json"), "{}").expect("write skills lock");
+
+        let state = AppState::new(
+            home.clone(),
+            home.join("settings"),
+            DotagentsRunner::new(home.clone(), DotagentsRuntimeManager::new()),
+        );
+        let inspection = state
+            .inspect_project_root(&project.display().to_string())
+            .expect("inspect");
+
+        assert!(inspection.has_agents_toml);
+        assert!(!inspection.has_agents_lock);
+        assert!(inspection.has_agents_dir);
+        assert!(inspection.has_skills_lock);
+        assert_eq!(inspection.read_only, Some(false));
+        assert_eq!(
+            state
+                .get_app_context()
+                .expect("context")
+                .active_project_context
+                .project_root,
+            None
+        );
+        assert!(state
+            .inspect_project_root(&temp.path().join("missing").display().to_string())
+            .is_err());
+    }
+
+    #[test]
+    fn list_reads_give_up_while_a_command_is_running() {
+        let temp = tempdir().expect("tempdir");
+        let home_dir = temp.path().join("home");
+        fs::create_dir_all(home_dir.join(".agents")).expect("agents dir");
+        fs::write(home_dir.join(".agents/agents.toml"), "version = 1\n").expect("agents.toml");
+
+        let state = AppState::new(
+            home_dir.clone(),
+            temp.path().join("settings"),
+            DotagentsRunner::new(home_dir, DotagentsRuntimeManager::new()),
+        );
+        state.set_scope(DotagentsScope::User).expect("set scope");
+        let _running = state.lock_dotagents_commands().expect("lock");
+
+        let error = state.list_skills().expect_err("busy");
+        assert!(error.contains("still running"), "{error}");
+    }
+
+    #[test]
+    fn overlapping_list_reads_share_the_command_lock() {
+        let lock = RwLock::new(());
+
+        let first = wait_for_read_lock(&lock, "dotagents").expect("first read");
+        let second = wait_for_read_lock(&lock, "dotagents").expect("second read");
+        drop((first, second));
+
+        let _running = lock.write().expect("write");
+        let error = wait_for_read_lock(&lock, "dotagents").expect_err("busy");
+        assert!(error.contains("still running"), "{error}");
+    }
 }
@@ -1,8 +1,186 @@
-use std::fs::File;
-use std::io::{BufReader, Read};
-use std::path::Path;
+use std::collections::VecDeque;
+use std::io::{self, Read};
+#[cfg(unix)]
+use std::os::unix::process::CommandExt;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
 
-const FRONTMATTER_READ_CAP_BYTES: u64 = 8 * 1024;
+/// Upper bound for a single vendor CLI process. Installs that clone several
+/// repositories stay well under this; anything longer is treated as hung.
+pub const DEFAULT_COMMAND_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+/// Most output kept per stream. The tail is kept because errors come last;
+/// anything earlier is replaced by a truncation marker.
+const MAX_CAPTURED_BYTES_PER_STREAM: usize = 256 * 1024;
+const PROCESS_POLL_INTERVAL: Duration = Duration::from_millis(25);
+/// How long to keep draining output pipes once the process has exited or
+/// been killed. Bounded because a detached grandchild could hold them open.
+const OUTPUT_DRAIN_GRACE: Duration = Duration::from_secs(1);
+
+/// Output of a process run through [`run_with_timeout`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessOutput {
+    pub success: bool,
+    pub exit_code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+    pub timed_out: bool,
+}
+
+/// Run `command` to completion, killing it once `timeout` elapses. Output
+/// produced before the kill is kept so the transcript shows how far it got.
+///
+/// On unix the process runs in its own process group and the whole group is
+/// killed, so the `node` process `npx` spawns cannot outlive the timeout and
+/// keep writing after the command lock is released. There is no cancel
+/// control; the timeout is the only way a running command is stopped.
+pub fn run_with_timeout(command: &mut Command, timeout: Duration) -> io::Result<ProcessOutput> {
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    #[cfg(unix)]
+    command.process_group(0);
+    let mut child = command.spawn()?;
+    let stdout = StreamCapture::start(child.stdout.take());
+    let stderr = StreamCapture::start(child.stderr.take());
+
+    let status = wait_until(&mut child, Instant::now() + timeout)?;
+    let output_deadline = Instant::now() + OUTPUT_DRAIN_GRACE;
+
+    Ok(ProcessOutput {
+        success: status.is_some_and(|status| status.success()),
+        exit_code: status.and_then(|status| status.code()),
+        stdout: stdout.finish(output_deadline),
+        stderr: stderr.finish(output_deadline),
+        timed_out: status.is_none(),
+    })
+}
+
+/// Stderr for a command transcript, with a note appended when the process
+/// was killed by [`run_with_timeout`].
+pub fn transcript_stderr(
+    output: &ProcessOutput,
+    display_command: &str,
+    timeout: Duration,
+) -> String {
+    if !output.timed_out {
+        return output.stderr.clone();
+    }
+    combine_output(
+        &output.stderr,
+        &format!("{display_command} timed out after {timeout:?} and was terminated"),
+    )
+}
+
+fn wait_until(child: &mut Child, deadline: Instant) -> io::Result<Option<ExitStatus>> {
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if Instant::now() >= deadline {
+            // The process may exit between the check and the kill; either way
+            // it is gone once `wait` returns.
+            kill_process_tree(child);
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(PROCESS_POLL_INTERVAL);
+    }
+}
+
+#[cfg(unix)]
+fn kill_process_tree(child: &mut Child) {
+    // `process_group(0)` made the child a group leader, so its pid is the
+    // group id shared with everything it spawned.
+    match libc::pid_t::try_from(child.id()) {
+        // SAFETY: `kill` has no memory-safety preconditions; a stale group id
+        // only makes it fail with ESRCH.
+        Ok(pgid) => unsafe {
+            libc::kill(-pgid, libc::SIGKILL);
+        },
+        Err(_) => {
+            let _ = child.kill();
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn kill_process_tree(child: &mut Child) {
+    let _ = child.kill();
+}
+
+/// The last [`MAX_CAPTURED_BYTES_PER_STREAM`] bytes of a stream, plus a count
+/// of what was dropped before them.
+#[derive(Default)]
+struct CapturedTail {
+    bytes: VecDeque<u8>,
+    dropped: usize,
+}
+
+impl CapturedTail {
+    fn push(&mut self, chunk: &[u8]) {
+        self.bytes.extend(chunk);
+        let excess = self
+            .bytes
+            .len()
+            .saturating_sub(MAX_CAPTURED_BYTES_PER_STREAM);
+        if excess > 0 {
+            self.bytes.drain(..excess);
+            self.dropped += excess;
+        }
+    }
+
+    fn render(&self) -> String {
+        let (front, back) = self.bytes.as_slices();
+        let text = String::from_utf8_lossy(&[front, back].concat())
+            .trim()
+            .to_string();
+        if self.dropped == 0 {
+            return text;
+        }
+        format!("[… {} earlier bytes truncated …]\n{text}", self.dropped)
+    }
+}
+
+struct StreamCapture {
+    buffer: Arc<Mutex<CapturedTail>>,
+    reader: Option<JoinHandle<()>>,
+}
+
+impl StreamCapture {
+    fn start(stream: Option<impl Read + Send + 'static>) -> Self {
+        let buffer = Arc::new(Mutex::new(CapturedTail::default()));
+        let reader = stream.map(|mut stream| {
+            let buffer = Arc::clone(&buffer);
+            thread::spawn(move || {
+                let mut chunk = [0u8; 8192];
+                while let Ok(read) = stream.read(&mut chunk) {
+                    if read == 0 {
+                        break;
+                    }
+                    if let Ok(mut buffer) = buffer.lock() {
+                        buffer.push(&chunk[..read]);
+                    }
+                }
+            })
+        });
+        Self { buffer, reader }
+    }
+
+    fn finish(self, deadline: Instant) -> String {
+        if let Some(reader) = self.reader {
+            while !reader.is_finished() && Instant::now() < deadline {
+                thread::sleep(PROCESS_POLL_INTERVAL);
+            }
+        }
+        self.buffer
+            .lock()
+            .map(|buffer| buffer.render())
+            .unwrap_or_default()
+    }
+}
 
 pub fn combine_output(primary: &str, secondary: &str) -> String {
     match (primary.trim().is_empty(), secondary.trim().is_empty()) {
@@ -20,35 +198,62 @@ pub fn require_non_empty(value: &str, message: &str) -> Result<(), String> {
     Ok(())
 }
 
-pub fn read_skill_description(path: &Path) -> Option<String> {
-    let file = File::open(path).ok()?;
-    let mut reader = BufReader::new(file).take(FRONTMATTER_READ_CAP_BYTES);
-    let mut header = String::new();
-    reader.read_to_string(&mut header).ok()?;
-
-    let trimmed = header.trim_start_matches('\u{feff}').trim_start();
-    let after_open = trimmed
-        .strip_prefix("---")?
-        .trim_start_matches(['\n', '\r']);
-    let close_pos = after_open.find("\n---")?;
-    let frontmatter = &after_open[..close_pos];
-
-    for line in frontmatter.lines() {
-        let line = line.trim();
-        if let Some(rest) = line.strip_prefix("description:") {
-            let value = rest.trim().trim_matches('"').trim_matches('\'');
-            if !value.is_empty() {
-                return Some(value.to_string());
-            }
+/// Longest skill name agents accept as a directory name (Agent Skills spec).
+const MAX_SKILL_NAME_LEN: usize = 64;
+
+/// Check a skill name against the constraints agents place on skill
+/// directory names: lowercase ASCII letters, digits, and single hyphens,
+/// at most 64 characters. The error suggests a normalized name.
+pub fn validate_skill_name(name: &str) -> Result<(), String> {
+    let valid = !name.is_empty()
+        && name.len() <= MAX_SKILL_NAME_LEN
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        && !name.starts_with('-')
+        && !name.ends_with('-')
+        && !name.contains("--");
+    if valid {
+        return Ok(());
+    }
+
+    let suggestion = suggest_skill_name(name);
+    if suggestion.is_empty() {
+        return Err(format!(
+            "skill name '{name}' must use lowercase letters, digits, and single hyphens"
+        ));
+    }
+    Err(format!(
+        "skill name '{name}' must use lowercase letters, digits, and single hyphens \
+         (at most {MAX_SKILL_NAME_LEN} characters); try '{suggestion}'"
+    ))
+}
+
+fn suggest_skill_name(name: &str) -> String {
+    let mut suggestion = String::new();
+    for c in name.trim().chars() {
+        if c.is_ascii_alphanumeric() {
+            suggestion.push(c.to_ascii_lowercase());
+        } else if !suggestion.is_empty() && !suggestion.ends_with('-') {
+            suggestion.push('-');
         }
     }
-    None
+    suggestion.truncate(MAX_SKILL_NAME_LEN);
+    suggestion.trim_end_matches('-').to_string()
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{combine_output, read_skill_description, require_non_empty};
-    use std::fs;
+    #[cfg(unix)]
+    use super::MAX_CAPTURED_BYTES_PER_STREAM;
+    use super::{
+        combine_output, require_non_empty, run_with_timeout, transcript_stderr, validate_skill_name,
+    };
+    #[cfg(unix)]
+    use std::process::Command;
+    #[cfg(unix)]
+    use std::time::{Duration, Instant};
+    #[cfg(unix)]
     use tempfile::tempdir;
 
     #[test]
@@ -67,33 +272,108 @@ mod tests {
     }
 
     #[test]
-    fn read_skill_description_parses_frontmatter() {
-        let temp = tempdir().expect("tempdir");
-        let path = temp.path().join("SKILL.md");
-        fs::write(
-            &path,
-            "---\nname: foo\ndescription: A short skill\n---\n\nbody",
-        )
-        .expect("write");
-        assert_eq!(
-            read_skill_description(&path).as_deref(),
-            Some("A short skill")
-        );
+    fn validate_skill_name_accepts_kebab_case() {
+        assert!(validate_skill_name("lint").is_ok());
+        assert!(validate_skill_name("pr-review-2").is_ok());
+        assert!(validate_skill_name(&"a".repeat(64)).is_ok());
     }
 
     #[test]
-    fn read_skill_description_skips_when_no_frontmatter() {
-        let temp = tempdir().expect("tempdir");
-        let path = temp.path().join("SKILL.md");
-        fs::write(&path, "no frontmatter here").expect("write");
-        assert!(read_skill_description(&path).is_none());
+    fn validate_skill_name_rejects_and_suggests() {
+        let error = validate_skill_name("PR Review").expect_err("spaces and caps");
+        assert!(error.contains("try 'pr-review'"), "{error}");
+        let error = validate_skill_name("-lint--fix-").expect_err("hyphens");
+        assert!(error.contains("try 'lint-fix'"), "{error}");
+        assert!(validate_skill_name(&"a".repeat(65)).is_err());
+        let error = validate_skill_name("???").expect_err("no usable chars");
+        assert!(!error.contains("try"), "{error}");
     }
 
     #[test]
-    fn read_skill_description_strips_bom() {
+    #[cfg(unix)]
+    fn run_with_timeout_captures_output_and_exit_code() {
+        let mut command = Command::new("sh");
+        command.arg("-c").arg("echo out; echo err >&2; exit 3");
+
+        let output = run_with_timeout(&mut command, Duration::from_secs(10)).expect("run");
+
+        assert!(!output.success);
+        assert!(!output.timed_out);
+        assert_eq!(output.exit_code, Some(3));
+        assert_eq!(output.stdout, "out");
+        assert_eq!(output.stderr, "err");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn run_with_timeout_kills_hung_process_and_keeps_partial_output() {
+        let mut command = Command::new("sh");
+        command.arg("-c").arg("echo started; exec sleep 30");
+
+        let start = Instant::now();
+        let output = run_with_timeout(&mut command, Duration::from_millis(300)).expect("run");
+
+        assert!(output.timed_out);
+        assert!(!output.success);
+        assert_eq!(output.exit_code, None);
+        assert_eq!(output.stdout, "started");
+        assert!(start.elapsed() < Duration::from_secs(10));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn run_with_timeout_kills_processes_spawned_by_the_child() {
         let temp = tempdir().expect("tempdir");
-        let path = temp.path().join("SKILL.md");
-        fs::write(&path, "\u{feff}---\ndescription: X\n---\n").expect("write");
-        assert_eq!(read_skill_description(&path).as_deref(), Some("X"));
+        let marker = temp.path().join("written-after-timeout");
+        let mut command = Command::new("sh");
+        command
+            .arg("-c")
+            .arg("(sleep 1; touch \"$1\") & echo started; wait")
+            .arg("sh")
+            .arg(&marker);
+
+        let output = run_with_timeout(&mut command, Duration::from_millis(300)).expect("run");
+        std::thread::sleep(Duration::from_millis(1500));
+
+        assert!(output.timed_out);
+        assert_eq!(output.stdout, "started");
+        assert!(!marker.exists(), "grandchild outlived the timeout");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn transcript_stderr_notes_timeouts_only() {
+        let mut command = Command::new("sh");
+        command.arg("-c").arg("echo warn >&2; exec sleep 30");
+        let timed_out = run_with_timeout(&mut command, Duration::from_millis(200)).expect("run");
+        assert_eq!(
+            transcript_stderr(&timed_out, "skills list", Duration::from_millis(200)),
+            "warn\nskills list timed out after 200ms and was terminated"
+        );
+
+        let mut command = Command::new("sh");
+        command.arg("-c").arg("echo warn >&2");
+        let finished = run_with_timeout(&mut command, Duration::from_secs(10)).expect("run");
+        assert_eq!(
+            transcript_stderr(&finished, "skills list", Duration::from_secs(10)),
+            "warn"
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn run_with_timeout_keeps_only_the_tail_of_large_output() {
+        let mut command = Command::new("sh");
+        command
+            .arg("-c")
+            .arg("head -c 600000 /dev/zero | tr '\\0' a; echo; echo done");
+
+        let output = run_with_timeout(&mut command, Duration::from_secs(30)).expect("run");
+
+        assert!(output
+            .stdout
+            .starts_with("[… 337862 earlier bytes truncated …]\n"));
+        assert!(output.stdout.ends_with("\ndone"));
+        assert!(output.stdout.len() < MAX_CAPTURED_BYTES_PER_STREAM + 64);
     }
 }
@@ -1,10 +1,13 @@
-use crate::cli_util::{combine_output, read_skill_description, require_non_empty};
+use crate::cli_util::{
+    combine_output, require_non_empty, run_with_timeout, transcript_stderr, validate_skill_name,
+    DEFAULT_COMMAND_TIMEOUT,
+};
 use crate::dotagents_runtime::{DotagentsRuntimeManager, DotagentsRuntimeStatus};
+use crate::frontmatter::read_skill_metadata;
 use crate::settings::DotagentsScope;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
-use std::process::Command;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DotagentsExecutionContext {
@@ -20,10 +23,19 @@ pub struct DotagentsSkillListItem {
     pub status: DotagentsSkillStatus,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Summary taken from the `SKILL.md` body when no description is declared.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub derived_description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub commit: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub wildcard: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -105,11 +117,22 @@ pub struct DotagentsCommandResult {
 pub struct DotagentsRunner {
     runtime: DotagentsRuntimeManager,
     home_dir: PathBuf,
+    command_timeout: Duration,
 }
 
 impl DotagentsRunner {
     pub fn new(home_dir: PathBuf, runtime: DotagentsRuntimeManager) -> Self {
-        Self { runtime, home_dir }
+        Self {
+            runtime,
+            home_dir,
+            command_timeout: DEFAULT_COMMAND_TIMEOUT,
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn with_command_timeout(mut self, timeout: Duration) -> Self {
+        self.command_timeout = timeout;
+        self
     }
 
     fn dotagents_home(&self) -> PathBuf {
@@ -133,7 +156,7 @@ impl DotagentsRunner {
             self.run_read_command(context, &[String::from("list"), String::from("--json")])?;
         let mut skills = parse_skill_list(&raw)?;
         let skills_dir = self.dotagents_home().join("skills");
-        enrich_skill_descriptions(&mut skills, &skills_dir);
+        enrich_skill_metadata(&mut skills, &skills_dir);
         Ok(skills)
     }
 
@@ -209,9 +232,7 @@ impl DotagentsRunner {
         let display_command = render_display_command(context.scope, args);
         let start = Instant::now();
 
-        let mut command = Command::new("npx");
-        command.arg("--yes");
-        command.arg(self.runtime.npx_package_spec());
+        let mut command = self.runtime.dotagents_command();
 
         if matches!(context.scope, DotagentsScope::User) {
             command.arg("--user");
@@ -227,20 +248,20 @@ impl DotagentsRunner {
         command.env("NO_COLOR", "1");
         command.env("DOTAGENTS_NO_COLOR", "1");
 
-        let output = command
-            .output()
+        let output = run_with_timeout(&mut command, self.command_timeout)
             .map_err(|error| format!("failed to launch npx: {error}"))?;
         let duration_ms = start.elapsed().as_millis() as u64;
+        let stderr = transcript_stderr(&output, &display_command, self.command_timeout);
 
         Ok(DotagentsCommandResult {
-            success: output.status.success(),
+            success: output.success,
             command: display_command,
             cwd: context.cwd.display().to_string(),
             scope: context.scope,
-            exit_code: output.status.code(),
+            exit_code: output.exit_code,
             duration_ms,
-            stdout: String::from_utf8_lossy(&output.stdout).trim().to_string(),
-            stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            stdout: output.stdout,
+            stderr,
         })
     }
 }
@@ -274,6 +295,7 @@ pub fn build_command_args(request: &DotagentsCommandRequest) -> Result<Vec<Strin
             if *all {
                 args.push(String::from("--all"));
             } else if let Some(name) = name {
+                validate_skill_name(name.trim())?;
                 args.push(String::from("--name"));
                 args.push(name.trim().to_string());
             }
@@ -358,15 +380,33 @@ pub fn build_command_args(request: &DotagentsCommandRequest) -> Result<Vec<Strin
     }
 }
 
+/// Render the transcript form of a dotagents invocation. Values passed via
+/// `--env KEY=value` and `--header Name:value` are masked so secrets never
+/// reach the Output pane; the real process still receives them.
 pub fn render_display_command(scope: DotagentsScope, args: &[String]) -> String {
     let mut pieces = vec![String::from("dotagents")];
     if matches!(scope, DotagentsScope::User) {
         pieces.push(String::from("--user"));
     }
-    pieces.extend(args.iter().cloned());
+    let mut previous: Option<&str> = None;
+    for arg in args {
+        pieces.push(match previous {
+            Some("--env") => redact_after(arg, '='),
+            Some("--header") => redact_after(arg, ':'),
+            _ => arg.clone(),
+        });
+        previous = Some(arg.as_str());
+    }
     pieces.join(" ")
 }
 
+fn redact_after(value: &str, separator: char) -> String {
+    match value.split_once(separator) {
+        Some((name, secret)) if !secret.trim().is_empty() => format!("{name}{separator}***"),
+        _ => value.to_string(),
+    }
+}
+
 pub fn parse_skill_list(raw: &str) -> Result<Vec<DotagentsSkillListItem>, String> {
     parse_declared_list(raw, "No skills declared in agents.toml.", "skill")
 }
@@ -388,15 +428,19 @@ where
         .map_err(|error| format!("failed to parse {label} list JSON: {error}"))
 }
 
-fn enrich_skill_descriptions(skills: &mut [DotagentsSkillListItem], skills_dir: &Path) {
+fn enrich_skill_metadata(skills: &mut [DotagentsSkillListItem], skills_dir: &Path) {
     for skill in skills.iter_mut() {
-        if skill.description.is_some() {
-            continue;
-        }
         let skill_md = skills_dir.join(&skill.name).join("SKILL.md");
-        if let Some(desc) = read_skill_description(&skill_md) {
-            skill.description = Some(desc);
+        let Some(metadata) = read_skill_metadata(&skill_md) else {
+            continue;
+        };
+        skill.description = skill.description.take().or(metadata.description);
+        if skill.description.is_none() {
+            skill.derived_description = metadata.derived_description;
         }
+        skill.version = skill.version.take().or(metadata.version);
+        skill.author = skill.author.take().or(metadata.author);
+        skill.license = skill.license.take().or(metadata.license);
     }
 }
 
@@ -419,13 +463,19 @@ fn preflight_failure_result(
 
 #[cfg(test)]
 mod tests {
+    use super::DotagentsExecutionContext;
     use super::{
-        build_command_args, parse_mcp_list, parse_skill_list, render_display_command,
-        DotagentsCommandRequest, DotagentsRunner,
+        build_command_args, enrich_skill_metadata, parse_mcp_list, parse_skill_list,
+        render_display_command, DotagentsCommandRequest, DotagentsRunner,
     };
     use crate::dotagents_runtime::DotagentsRuntimeManager;
     use crate::settings::DotagentsScope;
+    use std::fs;
+    #[cfg(unix)]
+    use std::os::unix::fs::PermissionsExt;
     use std::path::PathBuf;
+    use std::time::Duration;
+    use tempfile::tempdir;
 
     #[test]
     fn scope_to_argv_inserts_user_flag_only_for_user_scope() {
@@ -440,6 +490,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn display_command_masks_env_and_header_values() {
+        let args = build_command_args(&DotagentsCommandRequest::McpAddHttp {
+            name: String::from("remote"),
+            url: String::from("https://mcp.example.com/sse"),
+            headers: vec![String::from("Authorization: Bearer secret-token")],
+            env: vec![
+                String::from("API_TOKEN=abc123"),
+                String::from("GITHUB_TOKEN"),
+            ],
+        })
+        .expect("mcp add http");
+
+        assert_eq!(
+            render_display_command(DotagentsScope::Project, &args),
+            "dotagents mcp add remote --url https://mcp.example.com/sse \
+             --header Authorization:*** --env API_TOKEN=*** --env GITHUB_TOKEN"
+        );
+    }
+
     #[test]
     fn parse_vendor_skill_list_json() {
         let parsed = parse_skill_list(
@@ -450,6 +520,29 @@ mod tests {
         assert_eq!(parsed[0].wildcard.as_deref(), Some("owner/repo"));
     }
 
+    #[test]
+    fn skill_frontmatter_metadata_fills_missing_fields() {
+        let temp = tempdir().expect("tempdir");
+        let skill_dir = temp.path().join("lint");
+        fs::create_dir_all(&skill_dir).expect("skill dir");
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: lint\ndescription: Lints code\nlicense: MIT\nmetadata:\n  author: acme\n  version: \"1.0.0\"\n---\n",
+        )
+        .expect("write SKILL.md");
+        let mut skills = parse_skill_list(
+            r#"[{"name":"lint","source":"owner/repo","status":"ok","description":"From vendor"}]"#,
+        )
+        .expect("parse skill list");
+
+        enrich_skill_metadata(&mut skills, temp.path());
+
+        assert_eq!(skills[0].description.as_deref(), Some("From vendor"));
+        assert_eq!(skills[0].version.as_deref(), Some("1.0.0"));
+        assert_eq!(skills[0].author.as_deref(), Some("acme"));
+        assert_eq!(skills[0].license.as_deref(), Some("MIT"));
+    }
+
     #[test]
     fn parse_vendor_mcp_list_json() {
         let parsed = parse_mcp_list(
@@ -557,6 +650,17 @@ mod tests {
         assert!(error.contains("requires either an explicit name or wildcard mode"));
     }
 
+    #[test]
+    fn skill_add_rejects_names_agents_cannot_load() {
+        let error = build_command_args(&DotagentsCommandRequest::SkillAdd {
+            source: String::from("owner/repo"),
+            name: Some(String::from("My Skill")),
+            all: false,
+        })
+        .expect_err("invalid name");
+        assert!(error.contains("try 'my-skill'"));
+    }
+
     #[test]
     fn dotagents_home_is_always_derived_from_user_home() {
         let runner =
@@ -564,4 +668,66 @@ mod tests {
 
         assert_eq!(runner.dotagents_home(), PathBuf::from("/tmp/home/.agents"));
     }
+
+    #[test]
+    #[cfg(unix)]
+    fn commands_spawn_through_the_runtime_npx_binary() {
+        let temp = tempdir().expect("tempdir");
+        let script_path = temp.path().join("npx");
+        fs::write(&script_path, "#!/bin/sh\necho \"$@\"\n").expect("write script");
+        let mut perms = fs::metadata(&script_path).expect("metadata").permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&script_path, perms).expect("chmod");
+
+        let runner = DotagentsRunner::new(
+            temp.path().to_path_buf(),
+            DotagentsRuntimeManager::new().with_npx_binary(script_path),
+        );
+        let context = DotagentsExecutionContext {
+            scope: DotagentsScope::User,
+            cwd: temp.path().to_path_buf(),
+        };
+        let result = runner
+            .run_command(&context, &DotagentsCommandRequest::Sync)
+            .expect("run sync");
+
+        assert!(result.success);
+        assert_eq!(result.command, "dotagents --user sync");
+        assert_eq!(result.stdout, "--yes @sentry/dotagents@1.4.0 --user sync");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn hung_commands_time_out_with_captured_output() {
+        let temp = tempdir().expect("tempdir");
+        let script_path = temp.path().join("npx");
+        fs::write(
+            &script_path,
+            "#!/bin/sh\nif [ \"$1\" = \"--version\" ]; then exit 0; fi\necho \"resolving packages\"\nexec sleep 30\n",
+        )
+        .expect("write script");
+        let mut perms = fs::metadata(&script_path).expect("metadata").permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&script_path, perms).expect("chmod");
+
+        let runner = DotagentsRunner::new(
+            temp.path().to_path_buf(),
+            DotagentsRuntimeManager::new().with_npx_binary(script_path),
+        )
+        .with_command_timeout(Duration::from_millis(300));
+        let context = DotagentsExecutionContext {
+            scope: DotagentsScope::Project,
+            cwd: temp.path().to_path_buf(),
+        };
+        let result = runner
+            .run_command(&context, &DotagentsCommandRequest::Install { frozen: true })
+            .expect("run install");
+
+        assert!(!result.success);
+        assert_eq!(result.exit_code, None);
+        assert_eq!(result.stdout, "resolving packages");
+        assert!(result
+            .stderr
+            .contains("dotagents install --frozen timed out after 300ms"));
+    }
 }
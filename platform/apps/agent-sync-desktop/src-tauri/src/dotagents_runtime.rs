@@ -1,4 +1,5 @@
 use serde::Serialize;
+use std::io::ErrorKind;
 use std::path::PathBuf;
 use std::process::Command;
 
@@ -41,21 +42,47 @@ impl DotagentsRuntimeManager {
         format!("@sentry/dotagents@{}", self.expected_version)
     }
 
+    /// `npx --yes @sentry/dotagents@<pinned>`; callers append the dotagents
+    /// arguments. Every dotagents process is spawned through here.
+    pub fn dotagents_command(&self) -> Command {
+        let mut command = self.npx_command();
+        command.arg("--yes").arg(self.npx_package_spec());
+        command
+    }
+
     pub fn check_npx_available(&self) -> Result<(), String> {
-        self.npx_command()
+        let program = self.npx_program();
+        let output = self
+            .npx_command()
             .arg("--version")
             .output()
-            .map_err(|_| String::from("npx is not available — install Node.js and npm"))?;
-        Ok(())
+            .map_err(|error| match error.kind() {
+                ErrorKind::NotFound => format!(
+                    "npx is not available — install Node.js and npm (tried {})",
+                    program.display()
+                ),
+                _ => format!(
+                    "npx is not available — failed to launch {}: {error}",
+                    program.display()
+                ),
+            })?;
+
+        if output.status.success() {
+            return Ok(());
+        }
+        Err(format!(
+            "npx is not available — {} --version exited with {}: {}",
+            program.display(),
+            output.status,
+            combine_output(&output.stderr, &output.stdout)
+        ))
     }
 
     pub fn check_pinned_cli_available(&self) -> Result<(), String> {
         self.check_npx_available()?;
 
         let output = self
-            .npx_command()
-            .arg("--yes")
-            .arg(self.npx_package_spec())
+            .dotagents_command()
             .arg("--version")
             .output()
             .map_err(|error| format!("failed to launch npx: {error}"))?;
@@ -80,11 +107,14 @@ impl DotagentsRuntimeManager {
         ))
     }
 
+    fn npx_program(&self) -> PathBuf {
+        self.npx_binary
+            .clone()
+            .unwrap_or_else(|| PathBuf::from("npx"))
+    }
+
     fn npx_command(&self) -> Command {
-        match &self.npx_binary {
-            Some(path) => Command::new(path),
-            None => Command::new("npx"),
-        }
+        Command::new(self.npx_program())
     }
 
     #[cfg(test)]
@@ -190,4 +220,39 @@ exit 1
             .expect_err("probe should fail");
         assert!(error.contains("failed to resolve @sentry/dotagents@1.4.0 via npx"));
     }
+
+    #[test]
+    fn npx_probe_reports_the_binary_it_tried() {
+        let temp = tempdir().expect("tempdir");
+        let missing = temp.path().join("missing-npx");
+
+        let manager = DotagentsRuntimeManager::new().with_npx_binary(missing.clone());
+        let error = manager
+            .check_npx_available()
+            .expect_err("missing binary should fail");
+
+        assert!(error.contains("npx is not available"));
+        assert!(error.contains(&missing.display().to_string()));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn npx_probe_rejects_failing_version_check() {
+        let temp = tempdir().expect("tempdir");
+        let script_path = temp.path().join("npx");
+        fs::write(
+            &script_path,
+            "#!/bin/sh\necho \"node: not found\" >&2\nexit 127\n",
+        )
+        .expect("write script");
+        let mut perms = fs::metadata(&script_path).expect("metadata").permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&script_path, perms).expect("chmod");
+
+        let manager = DotagentsRuntimeManager::new().with_npx_binary(script_path);
+        let error = manager
+            .check_npx_available()
+            .expect_err("probe should fail");
+        assert!(error.contains("node: not found"));
+    }
 }
@@ -0,0 +1,455 @@
+use serde_yaml_ng::{Mapping, Value};
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+
+const FRONTMATTER_READ_CAP_BYTES: u64 = 8 * 1024;
+
+/// Longest description derived from a skill body, in characters.
+const DERIVED_DESCRIPTION_MAX_CHARS: usize = 200;
+
+/// Read the start of a markdown file.
+///
+/// Only the first [`FRONTMATTER_READ_CAP_BYTES`] are read, so a skill with a
+/// huge body never gets loaded into memory just to render its description.
+fn read_markdown_head(path: &Path) -> Option<String> {
+    let file = File::open(path).ok()?;
+    let mut reader = BufReader::new(file).take(FRONTMATTER_READ_CAP_BYTES);
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes).ok()?;
+    Some(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Split the `---` delimited frontmatter of a markdown document into a YAML
+/// mapping and the body that follows it. Unknown fields are kept in the
+/// mapping. The app only reads frontmatter and never writes `SKILL.md` back,
+/// so there is no formatting to preserve.
+///
+/// Hand-written frontmatter is often not valid YAML (an unquoted
+/// `description: Use when: ...` is a parse error), so a block that fails to
+/// parse falls back to [`scan_top_level_fields`].
+pub fn split_frontmatter(contents: &str) -> Option<(Mapping, &str)> {
+    let trimmed = contents.trim_start_matches('\u{feff}').trim_start();
+    let (opening, after_open) = trimmed.strip_prefix("---")?.split_once('\n')?;
+    if !opening.trim().is_empty() {
+        return None;
+    }
+
+    let mut block = None;
+    let mut offset = 0;
+    for line in after_open.split_inclusive('\n') {
+        if line.trim_end() == "---" {
+            block = Some((&after_open[..offset], &after_open[offset + line.len()..]));
+            break;
+        }
+        offset += line.len();
+    }
+    let (yaml, body) = block?;
+
+    match serde_yaml_ng::from_str::<Value>(yaml) {
+        Ok(Value::Mapping(mut mapping)) => {
+            restore_numeric_text(&mut mapping, yaml);
+            Some((mapping, body))
+        }
+        Ok(Value::Null) => Some((Mapping::new(), body)),
+        Ok(_) => None,
+        Err(_) => Some((scan_top_level_fields(yaml), body)),
+    }
+}
+
+/// YAML reads an unquoted `version: 1.10` as the number 1.1. Put the text
+/// as written back for top-level numeric fields, so versions display the way
+/// the author typed them.
+fn restore_numeric_text(mapping: &mut Mapping, yaml: &str) {
+    let raw = scan_top_level_fields(yaml);
+    for (key, value) in mapping.iter_mut() {
+        if !value.is_number() {
+            continue;
+        }
+        if let Some(Value::String(text)) = raw.get(key) {
+            let text = text.split(" #").next().unwrap_or_default().trim();
+            *value = Value::String(text.to_string());
+        }
+    }
+}
+
+/// Lenient reader for frontmatter that is not valid YAML: every unindented
+/// `key: value` line becomes a string field, with surrounding quotes removed.
+/// Everything after the first `: ` separator is the value, so colons inside
+/// it survive.
+fn scan_top_level_fields(yaml: &str) -> Mapping {
+    let mut mapping = Mapping::new();
+    for line in yaml.lines() {
+        if line.starts_with([' ', '\t', '#', '-']) {
+            continue;
+        }
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let key = key.trim();
+        if key.is_empty() {
+            continue;
+        }
+        let value = value.trim();
+        let value = value
+            .strip_prefix('"')
+            .and_then(|rest| rest.strip_suffix('"'))
+            .or_else(|| {
+                value
+                    .strip_prefix('\'')
+                    .and_then(|rest| rest.strip_suffix('\''))
+            })
+            .unwrap_or(value);
+        let key = Value::String(key.to_string());
+        if !mapping.contains_key(&key) {
+            mapping.insert(key, Value::String(value.to_string()));
+        }
+    }
+    mapping
+}
+
+/// Look up a scalar frontmatter field and render it as a trimmed string.
+pub fn frontmatter_string(frontmatter: &Mapping, key: &str) -> Option<String> {
+    let value = match frontmatter.get(key)? {
+        Value::String(value) => value.trim().to_string(),
+        Value::Number(value) => value.to_string(),
+        Value::Bool(value) => value.to_string(),
+        _ => return None,
+    };
+    (!value.is_empty()).then_some(value)
+}
+
+/// Display metadata a `SKILL.md` frontmatter may carry. `version`, `author`,
+/// and `license` are read from the top level or from the nested `metadata`
+/// mapping the Agent Skills spec recommends.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SkillMetadata {
+    pub description: Option<String>,
+    pub version: Option<String>,
+    pub author: Option<String>,
+    pub license: Option<String>,
+    /// Fallback summary taken from the body when `description` is missing.
+    pub derived_description: Option<String>,
+}
+
+pub fn read_skill_metadata(path: &Path) -> Option<SkillMetadata> {
+    let head = read_markdown_head(path)?;
+    if let Some((frontmatter, body)) = split_frontmatter(&head) {
+        let mut metadata = skill_metadata(&frontmatter);
+        if metadata.description.is_none() {
+            metadata.derived_description = derive_description(body);
+        }
+        return Some(metadata);
+    }
+    if head
+        .trim_start_matches('\u{feff}')
+        .trim_start()
+        .starts_with("---")
+    {
+        // Unterminated or non-mapping frontmatter: the YAML would otherwise
+        // read as a paragraph.
+        return Some(SkillMetadata::default());
+    }
+    Some(SkillMetadata {
+        derived_description: derive_description(&head),
+        ..SkillMetadata::default()
+    })
+}
+
+pub fn skill_metadata(frontmatter: &Mapping) -> SkillMetadata {
+    let nested = match frontmatter.get("metadata") {
+        Some(Value::Mapping(nested)) => Some(nested),
+        _ => None,
+    };
+    let field = |key: &str| {
+        frontmatter_string(frontmatter, key)
+            .or_else(|| nested.and_then(|nested| frontmatter_string(nested, key)))
+    };
+    // Only text versions: a nested unquoted `1.10` has already lost its
+    // trailing zero, and showing "1.1" would be wrong.
+    let version = |mapping: &Mapping| match mapping.get("version") {
+        Some(Value::String(_)) => frontmatter_string(mapping, "version"),
+        _ => None,
+    };
+    SkillMetadata {
+        description: frontmatter_string(frontmatter, "description"),
+        version: version(frontmatter).or_else(|| nested.and_then(version)),
+        author: field("author"),
+        license: frontmatter_string(frontmatter, "license"),
+        derived_description: None,
+    }
+}
+
+/// Summarize a markdown body by its first paragraph, falling back to the
+/// first heading. Fenced code blocks and HTML comments are skipped.
+pub fn derive_description(body: &str) -> Option<String> {
+    let mut heading = None;
+    let mut paragraph = Vec::new();
+    let mut in_fence = false;
+    let mut in_comment = false;
+    for line in body.lines().map(str::trim) {
+        if in_comment {
+            in_comment = !line.contains("-->");
+            continue;
+        }
+        if line.starts_with("```") || line.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+        if let Some(comment) = line.strip_prefix("<!--") {
+            in_comment = !comment.contains("-->");
+            continue;
+        }
+        if line.is_empty() || line.starts_with('#') {
+            if !paragraph.is_empty() {
+                break;
+            }
+            if let Some(text) = line.strip_prefix('#') {
+                let text = text.trim_start_matches('#').trim();
+                if heading.is_none() && !text.is_empty() {
+                    heading = Some(text);
+                }
+            }
+            continue;
+        }
+        paragraph.push(line);
+    }
+
+    let summary = if paragraph.is_empty() {
+        heading?.to_string()
+    } else {
+        paragraph.join(" ")
+    };
+    if summary.chars().count() <= DERIVED_DESCRIPTION_MAX_CHARS {
+        return Some(summary);
+    }
+    let truncated = summary
+        .chars()
+        .take(DERIVED_DESCRIPTION_MAX_CHARS - 1)
+        .collect::<String>();
+    Some(format!("{}…", truncated.trim_end()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        derive_description, frontmatter_string, read_skill_metadata, skill_metadata,
+        split_frontmatter,
+    };
+    use serde_yaml_ng::Mapping;
+    use std::fs;
+    use std::path::Path;
+    use tempfile::tempdir;
+
+    fn parse_frontmatter(contents: &str) -> Option<Mapping> {
+        split_frontmatter(contents).map(|(frontmatter, _)| frontmatter)
+    }
+
+    fn read_skill_description(path: &Path) -> Option<String> {
+        read_skill_metadata(path)?.description
+    }
+
+    fn description(contents: &str) -> Option<String> {
+        frontmatter_string(&parse_frontmatter(contents)?, "description")
+    }
+
+    #[test]
+    fn read_skill_description_parses_frontmatter() {
+        let temp = tempdir().expect("tempdir");
+        let path = temp.path().join("SKILL.md");
+        fs::write(
+            &path,
+            "---\nname: foo\ndescription: A short skill\n---\n\nbody",
+        )
+        .expect("write");
+        assert_eq!(
+            read_skill_description(&path).as_deref(),
+            Some("A short skill")
+        );
+    }
+
+    #[test]
+    fn read_skill_description_skips_when_no_frontmatter() {
+        let temp = tempdir().expect("tempdir");
+        let path = temp.path().join("SKILL.md");
+        fs::write(&path, "no frontmatter here").expect("write");
+        assert!(read_skill_description(&path).is_none());
+    }
+
+    #[test]
+    fn read_skill_description_strips_bom() {
+        let temp = tempdir().expect("tempdir");
+        let path = temp.path().join("SKILL.md");
+        fs::write(&path, "\u{feff}---\ndescription: X\n---\n").expect("write");
+        assert_eq!(read_skill_description(&path).as_deref(), Some("X"));
+    }
+
+    #[test]
+    fn keeps_colons_inside_quoted_values() {
+        assert_eq!(
+            description("---\ndescription: \"Usage: run it: now\"\n---\n").as_deref(),
+            Some("Usage: run it: now")
+        );
+    }
+
+    #[test]
+    fn folds_multi_line_block_scalars() {
+        assert_eq!(
+            description("---\ndescription: >\n  Reviews pull requests\n  for style.\n---\n")
+                .as_deref(),
+            Some("Reviews pull requests for style.")
+        );
+        assert_eq!(
+            description("---\ndescription: |\n  line one\n  line two\n---\n").as_deref(),
+            Some("line one\nline two")
+        );
+    }
+
+    #[test]
+    fn ignores_description_keys_nested_in_other_fields() {
+        let contents =
+            "---\nname: foo\nmetadata:\n  description: nested\n  tags: [a, b]\n---\nbody";
+        assert!(description(contents).is_none());
+        let frontmatter = parse_frontmatter(contents).expect("frontmatter");
+        assert!(frontmatter.contains_key("metadata"));
+    }
+
+    #[test]
+    fn handles_crlf_line_endings() {
+        assert_eq!(
+            description("---\r\ndescription: Windows\r\n---\r\nbody").as_deref(),
+            Some("Windows")
+        );
+    }
+
+    #[test]
+    fn falls_back_to_line_scan_for_invalid_yaml() {
+        assert_eq!(
+            description("---\nname: foo\ndescription: Use when: the user asks\n---\n").as_deref(),
+            Some("Use when: the user asks")
+        );
+        assert_eq!(
+            description("---\ndescription: Helps with PDFs. Triggers: pdf\n---\n").as_deref(),
+            Some("Helps with PDFs. Triggers: pdf")
+        );
+        assert_eq!(
+            description("---\ndescription: [unclosed\n---\n").as_deref(),
+            Some("[unclosed")
+        );
+        let frontmatter = parse_frontmatter(
+            "---\nname: 'pdf'\ndescription: a: b\nmetadata:\n  author: nested\n---\n",
+        )
+        .expect("frontmatter");
+        assert_eq!(
+            frontmatter_string(&frontmatter, "name").as_deref(),
+            Some("pdf")
+        );
+        assert_eq!(frontmatter_string(&frontmatter, "author"), None);
+    }
+
+    #[test]
+    fn rejects_unterminated_or_non_mapping_frontmatter() {
+        assert!(parse_frontmatter("---\ndescription: never closed\n").is_none());
+        assert!(parse_frontmatter("---\n- just\n- a list\n---\n").is_none());
+    }
+
+    #[test]
+    fn empty_frontmatter_is_an_empty_mapping() {
+        let frontmatter = parse_frontmatter("---\n---\nbody").expect("frontmatter");
+        assert!(frontmatter.is_empty());
+    }
+
+    #[test]
+    fn reads_top_level_and_nested_metadata() {
+        let top_level = parse_frontmatter(
+            "---\ndescription: Lint\nversion: 1.2\nauthor: Jane\nlicense: MIT\n---\n",
+        )
+        .expect("frontmatter");
+        let metadata = skill_metadata(&top_level);
+        assert_eq!(metadata.description.as_deref(), Some("Lint"));
+        assert_eq!(metadata.version.as_deref(), Some("1.2"));
+        assert_eq!(metadata.author.as_deref(), Some("Jane"));
+        assert_eq!(metadata.license.as_deref(), Some("MIT"));
+
+        let nested = parse_frontmatter(
+            "---\nname: lint\nlicense: Apache-2.0\nmetadata:\n  author: acme\n  version: \"2.0.0\"\n---\n",
+        )
+        .expect("frontmatter");
+        let metadata = skill_metadata(&nested);
+        assert_eq!(metadata.description, None);
+        assert_eq!(metadata.version.as_deref(), Some("2.0.0"));
+        assert_eq!(metadata.author.as_deref(), Some("acme"));
+        assert_eq!(metadata.license.as_deref(), Some("Apache-2.0"));
+
+        let unquoted = parse_frontmatter("---\nversion: 1.10 # minor\n---\n").expect("frontmatter");
+        assert_eq!(skill_metadata(&unquoted).version.as_deref(), Some("1.10"));
+        let nested_number =
+            parse_frontmatter("---\nmetadata:\n  version: 1.10\n---\n").expect("frontmatter");
+        assert_eq!(skill_metadata(&nested_number).version, None);
+    }
+
+    #[test]
+    fn derives_description_from_first_paragraph() {
+        assert_eq!(
+            derive_description(
+                "\n# Lint\n\n<!-- internal -->\nChecks code style\nbefore commits.\n\nMore text.\n"
+            )
+            .as_deref(),
+            Some("Checks code style before commits.")
+        );
+        assert_eq!(
+            derive_description("```sh\nnpm test\n```\n## Release helper\n").as_deref(),
+            Some("Release helper")
+        );
+        assert_eq!(
+            derive_description("<!--\ninternal notes\n-->\nRuns the linter.\n").as_deref(),
+            Some("Runs the linter.")
+        );
+        assert_eq!(derive_description("\n\n"), None);
+
+        let long = derive_description(&"word ".repeat(100)).expect("long");
+        assert_eq!(long.chars().count(), 200);
+        assert!(long.ends_with('…'));
+    }
+
+    #[test]
+    fn derived_description_only_fills_missing_descriptions() {
+        let temp = tempdir().expect("tempdir");
+        let explicit = temp.path().join("explicit.md");
+        fs::write(&explicit, "---\ndescription: Explicit\n---\nBody text.\n").expect("write");
+        let missing = temp.path().join("missing.md");
+        fs::write(&missing, "---\nname: missing\n---\n# Title\n\nBody text.\n").expect("write");
+        let bare = temp.path().join("bare.md");
+        fs::write(&bare, "Plain markdown skill.\n").expect("write");
+        let colon = temp.path().join("colon.md");
+        fs::write(
+            &colon,
+            "---\ndescription: Use when: the user asks\n---\nBody text.\n",
+        )
+        .expect("write");
+
+        let explicit = read_skill_metadata(&explicit).expect("explicit");
+        assert_eq!(explicit.description.as_deref(), Some("Explicit"));
+        assert_eq!(explicit.derived_description, None);
+
+        let missing = read_skill_metadata(&missing).expect("missing");
+        assert_eq!(missing.description, None);
+        assert_eq!(missing.derived_description.as_deref(), Some("Body text."));
+
+        let colon = read_skill_metadata(&colon).expect("colon");
+        assert_eq!(
+            colon.description.as_deref(),
+            Some("Use when: the user asks")
+        );
+        assert_eq!(colon.derived_description, None);
+
+        let bare = read_skill_metadata(&bare).expect("bare");
+        assert_eq!(
+            bare.derived_description.as_deref(),
+            Some("Plain markdown skill.")
+        );
+    }
+}
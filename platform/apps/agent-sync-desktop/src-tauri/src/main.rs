@@ -4,12 +4,13 @@ mod app_state;
 mod cli_util;
 mod dotagents_runner;
 mod dotagents_runtime;
+mod frontmatter;
 mod open_path;
 mod settings;
 mod skills_runner;
 mod skills_runtime;
 
-use app_state::{AppContext, AppState, SkillsWorkspaceContext};
+use app_state::{AppContext, AppState, ProjectRootInspection, SkillsWorkspaceContext};
 use dotagents_runner::{
     DotagentsCommandRequest, DotagentsCommandResult, DotagentsMcpListItem, DotagentsSkillListItem,
 };
@@ -57,6 +58,14 @@ fn set_project_root(
     state.set_project_root(project_root)
 }
 
+#[tauri::command]
+fn inspect_project_root(
+    project_root: String,
+    state: tauri::State<AppState>,
+) -> Result<ProjectRootInspection, String> {
+    state.inspect_project_root(&project_root)
+}
+
 #[tauri::command]
 fn list_skills(state: tauri::State<AppState>) -> Result<Vec<DotagentsSkillListItem>, String> {
     state.list_skills()
@@ -147,6 +156,7 @@ fn main() {
             get_app_context,
             set_scope,
             set_project_root,
+            inspect_project_root,
             list_skills,
             list_mcp_servers,
             run_dotagents_command,
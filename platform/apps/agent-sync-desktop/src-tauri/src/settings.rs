@@ -109,7 +109,13 @@ impl SettingsStore {
 
         let serialized = serde_json::to_string_pretty(&normalize_settings(settings.clone()))
             .map_err(|error| format!("failed to serialize settings: {error}"))?;
-        fs::write(&self.settings_path, format!("{serialized}\n")).map_err(|error| {
+        let contents = format!("{serialized}\n");
+        // Skip no-op writes so re-selecting the same scope or folder does not
+        // touch the file (and its mtime) at all.
+        if fs::read_to_string(&self.settings_path).is_ok_and(|existing| existing == contents) {
+            return Ok(());
+        }
+        fs::write(&self.settings_path, contents).map_err(|error| {
             format!(
                 "failed to write settings file {}: {error}",
                 self.settings_path.display()
@@ -131,6 +137,7 @@ mod tests {
     };
     use crate::skills_runner::SkillsCliScope;
     use std::fs;
+    use std::time::{Duration, SystemTime};
     use tempfile::tempdir;
 
     #[test]
@@ -179,6 +186,33 @@ mod tests {
         assert!(loaded.skills_workspace_state.initialized);
     }
 
+    #[test]
+    fn save_skips_rewriting_unchanged_settings() {
+        let temp = tempdir().expect("tempdir");
+        let store = SettingsStore::new(temp.path());
+        let settings_path = temp.path().join("settings.json");
+        store
+            .save(&PersistedSettings::default())
+            .expect("first save");
+
+        let old_mtime = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        fs::File::options()
+            .write(true)
+            .open(&settings_path)
+            .expect("open settings")
+            .set_modified(old_mtime)
+            .expect("set mtime");
+
+        store
+            .save(&PersistedSettings::default())
+            .expect("second save");
+        let mtime = fs::metadata(&settings_path)
+            .expect("metadata")
+            .modified()
+            .expect("mtime");
+        assert_eq!(mtime, old_mtime);
+    }
+
     #[test]
     fn loads_v1_settings_with_defaulted_skills_workspace_state() {
         let temp = tempdir().expect("tempdir");
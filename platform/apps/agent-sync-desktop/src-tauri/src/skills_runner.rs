@@ -1,11 +1,13 @@
-use crate::cli_util::{combine_output, read_skill_description, require_non_empty};
+use crate::cli_util::{
+    combine_output, require_non_empty, run_with_timeout, transcript_stderr, DEFAULT_COMMAND_TIMEOUT,
+};
+use crate::frontmatter::read_skill_metadata;
 use crate::skills_runtime::{SkillsRuntimeManager, SkillsRuntimeStatus};
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::process::Command;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
@@ -39,10 +41,40 @@ pub struct SkillsCliListItem {
     pub agents: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source: Option<String>,
+    /// Version recorded by the Skills CLI (list output or `skills-lock.json`).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<String>,
+    /// Version the skill declares in its `SKILL.md` frontmatter. Kept apart
+    /// from `version` because the two can disagree.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub declared_version: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Summary taken from the `SKILL.md` body when no description is declared.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub derived_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<SkillsCliProvenance>,
+}
+
+/// Where an installed skill originally came from, as recorded by the Skills
+/// CLI in `skills-lock.json`. Only present when the lock entry carries at
+/// least one of these fields.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillsCliProvenance {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub installed_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -85,11 +117,22 @@ pub struct SkillsCliCommandResult {
 pub struct SkillsRunner {
     runtime: SkillsRuntimeManager,
     home_dir: PathBuf,
+    command_timeout: Duration,
 }
 
 impl SkillsRunner {
     pub fn new(home_dir: PathBuf, runtime: SkillsRuntimeManager) -> Self {
-        Self { runtime, home_dir }
+        Self {
+            runtime,
+            home_dir,
+            command_timeout: DEFAULT_COMMAND_TIMEOUT,
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn with_command_timeout(mut self, timeout: Duration) -> Self {
+        self.command_timeout = timeout;
+        self
     }
 
     pub fn runtime_status(&self) -> SkillsRuntimeStatus {
@@ -188,7 +231,7 @@ impl SkillsRunner {
         let display_command = render_display_command(args);
         let start = Instant::now();
 
-        let mut command = Command::new("npx");
+        let mut command = self.runtime.npx_command();
         command.arg("-y");
         command.arg(self.runtime.npx_package_spec());
 
@@ -200,21 +243,21 @@ impl SkillsRunner {
         command.env("HOME", &self.home_dir);
         command.env("NO_COLOR", "1");
 
-        let output = command
-            .output()
+        let output = run_with_timeout(&mut command, self.command_timeout)
             .map_err(|error| format!("failed to launch npx: {error}"))?;
         let duration_ms = start.elapsed().as_millis() as u64;
+        let stderr = transcript_stderr(&output, &display_command, self.command_timeout);
 
         Ok(SkillsCliCommandResult {
-            success: output.status.success(),
+            success: output.success,
             command: display_command,
             cwd: context.cwd.display().to_string(),
             scope: context.scope,
             agents,
-            exit_code: output.status.code(),
+            exit_code: output.exit_code,
             duration_ms,
-            stdout: String::from_utf8_lossy(&output.stdout).trim().to_string(),
-            stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            stdout: output.stdout,
+            stderr,
         })
     }
 
@@ -231,6 +274,7 @@ impl SkillsRunner {
 struct LockEntry {
     source: Option<String>,
     version: Option<String>,
+    provenance: Option<SkillsCliProvenance>,
 }
 
 pub fn build_command_args(request: &SkillsCliCommandRequest) -> Result<Vec<String>, String> {
@@ -397,10 +441,19 @@ fn enrich_skills(skills: &mut [SkillsCliListItem], lock: &HashMap<String, LockEn
             if skill.version.is_none() {
                 skill.version.clone_from(&entry.version);
             }
+            if skill.provenance.is_none() {
+                skill.provenance.clone_from(&entry.provenance);
+            }
         }
-        if skill.description.is_none() {
-            let skill_md = Path::new(&skill.path).join("SKILL.md");
-            skill.description = read_skill_description(&skill_md);
+        let skill_md = Path::new(&skill.path).join("SKILL.md");
+        if let Some(metadata) = read_skill_metadata(&skill_md) {
+            skill.description = skill.description.take().or(metadata.description);
+            if skill.description.is_none() {
+                skill.derived_description = metadata.derived_description;
+            }
+            skill.declared_version = metadata.version;
+            skill.author = skill.author.take().or(metadata.author);
+            skill.license = skill.license.take().or(metadata.license);
         }
     }
 }
@@ -436,15 +489,23 @@ fn read_lock_file(path: &Path) -> Option<HashMap<String, LockEntry>> {
 }
 
 fn lock_entry_from_value(value: &serde_json::Value) -> LockEntry {
-    let source = value
-        .get("source")
-        .and_then(|v| v.as_str())
-        .map(|s| s.to_string());
-    let version = value
-        .get("version")
-        .and_then(|v| v.as_str())
-        .map(|s| s.to_string());
-    LockEntry { source, version }
+    let string_field = |key: &str| {
+        value
+            .get(key)
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+    };
+    let provenance = SkillsCliProvenance {
+        source_type: string_field("sourceType"),
+        source_url: string_field("sourceUrl"),
+        installed_at: string_field("installedAt"),
+        updated_at: string_field("updatedAt"),
+    };
+    LockEntry {
+        source: string_field("source"),
+        version: string_field("version"),
+        provenance: (provenance != SkillsCliProvenance::default()).then_some(provenance),
+    }
 }
 
 fn preflight_failure_result(
@@ -478,9 +539,17 @@ fn require_at_least_one_agent(agents: &[String]) -> Result<(), String> {
 #[cfg(test)]
 mod tests {
     use super::{
-        agent_display, agent_kebab, build_command_args, parse_list_json, SkillsCliCommandRequest,
-        SkillsCliScope,
+        agent_display, agent_kebab, build_command_args, enrich_skills, parse_list_json,
+        read_lock_file, SkillsCliCommandRequest, SkillsCliScope, SkillsExecutionContext,
+        SkillsRunner,
     };
+    use crate::skills_runtime::SkillsRuntimeManager;
+    use std::fs;
+    #[cfg(unix)]
+    use std::os::unix::fs::PermissionsExt;
+    #[cfg(unix)]
+    use std::time::Duration;
+    use tempfile::tempdir;
 
     #[test]
     fn parses_list_json_into_items() {
@@ -499,6 +568,82 @@ mod tests {
         assert_eq!(parsed[0].scope, SkillsCliScope::Global);
     }
 
+    #[test]
+    fn lock_provenance_is_merged_into_list_items() {
+        let temp = tempdir().expect("tempdir");
+        let lock_path = temp.path().join("skills-lock.json");
+        fs::write(
+            &lock_path,
+            r#"{
+                "skills": {
+                    "adapt": {
+                        "source": "vercel-labs/agent-skills",
+                        "sourceType": "github",
+                        "sourceUrl": "https://github.com/vercel-labs/agent-skills.git",
+                        "installedAt": "2026-01-02T03:04:05Z"
+                    },
+                    "polish": { "source": "owner/repo" }
+                }
+            }"#,
+        )
+        .expect("write lock");
+        let lock = read_lock_file(&lock_path).expect("lock");
+        let mut skills = parse_list_json(
+            r#"[
+                {"name": "adapt", "path": "/nonexistent/adapt", "scope": "global", "agents": []},
+                {"name": "polish", "path": "/nonexistent/polish", "scope": "global", "agents": []}
+            ]"#,
+        )
+        .expect("parse");
+
+        enrich_skills(&mut skills, &lock);
+
+        let provenance = skills[0].provenance.as_ref().expect("provenance");
+        assert_eq!(provenance.source_type.as_deref(), Some("github"));
+        assert_eq!(
+            provenance.source_url.as_deref(),
+            Some("https://github.com/vercel-labs/agent-skills.git")
+        );
+        assert_eq!(
+            provenance.installed_at.as_deref(),
+            Some("2026-01-02T03:04:05Z")
+        );
+        assert!(provenance.updated_at.is_none());
+        assert_eq!(skills[1].source.as_deref(), Some("owner/repo"));
+        assert!(skills[1].provenance.is_none());
+    }
+
+    #[test]
+    fn lock_and_frontmatter_versions_stay_separate() {
+        let temp = tempdir().expect("tempdir");
+        let skill_dir = temp.path().join("adapt");
+        fs::create_dir_all(&skill_dir).expect("skill dir");
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: adapt\nversion: 2.1.0\nauthor: Jane\nlicense: MIT\n---\nBody\n",
+        )
+        .expect("write skill");
+        let lock_path = temp.path().join("skills-lock.json");
+        fs::write(
+            &lock_path,
+            r#"{"skills": {"adapt": {"version": "abc123"}}}"#,
+        )
+        .expect("write lock");
+        let lock = read_lock_file(&lock_path).expect("lock");
+        let mut skills = parse_list_json(&format!(
+            r#"[{{"name": "adapt", "path": {:?}, "scope": "global", "agents": []}}]"#,
+            skill_dir.to_string_lossy()
+        ))
+        .expect("parse");
+
+        enrich_skills(&mut skills, &lock);
+
+        assert_eq!(skills[0].version.as_deref(), Some("abc123"));
+        assert_eq!(skills[0].declared_version.as_deref(), Some("2.1.0"));
+        assert_eq!(skills[0].author.as_deref(), Some("Jane"));
+        assert_eq!(skills[0].license.as_deref(), Some("MIT"));
+    }
+
     #[test]
     fn empty_list_input_returns_empty() {
         assert!(parse_list_json("").expect("empty").is_empty());
@@ -613,4 +758,47 @@ mod tests {
         .expect("restore");
         assert_eq!(args, vec!["restore", "-p"]);
     }
+
+    #[test]
+    #[cfg(unix)]
+    fn hung_commands_time_out_with_captured_output() {
+        let temp = tempdir().expect("tempdir");
+        let script_path = temp.path().join("npx");
+        fs::write(
+            &script_path,
+            "#!/bin/sh\nif [ \"$1\" = \"--version\" ]; then exit 0; fi\necho \"cloning repository\"\nexec sleep 30\n",
+        )
+        .expect("write script");
+        let mut perms = fs::metadata(&script_path).expect("metadata").permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&script_path, perms).expect("chmod");
+
+        let runner = SkillsRunner::new(
+            temp.path().to_path_buf(),
+            SkillsRuntimeManager::new(None).with_npx_binary(script_path),
+        )
+        .with_command_timeout(Duration::from_millis(300));
+        let context = SkillsExecutionContext {
+            scope: SkillsCliScope::Project,
+            cwd: temp.path().to_path_buf(),
+        };
+        let result = runner
+            .run_command(
+                &context,
+                &SkillsCliCommandRequest::Update {
+                    names: Vec::new(),
+                    scope: SkillsCliScope::Project,
+                },
+            )
+            .expect("run update");
+
+        assert!(!result.success);
+        assert_eq!(result.exit_code, None);
+        assert_eq!(result.stdout, "cloning repository");
+        assert!(
+            result.stderr.contains("timed out after 300ms"),
+            "{}",
+            result.stderr
+        );
+    }
 }
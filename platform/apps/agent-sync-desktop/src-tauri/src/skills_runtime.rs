@@ -89,7 +89,6 @@ impl SkillsRuntimeManager {
     }
 
     #[cfg(test)]
-    #[allow(dead_code)]
     pub(crate) fn with_npx_binary(mut self, path: PathBuf) -> Self {
         self.npx_binary = Some(path);
         self
@@ -1,6 +1,7 @@
 import { render, screen, waitFor } from "@testing-library/react";
 import userEvent from "@testing-library/user-event";
 import { beforeEach, describe, expect, it, vi } from "vitest";
+import { open as openDirectoryDialog } from "@tauri-apps/plugin-dialog";
 import { App } from "./App";
 import * as tauriApi from "./tauriApi";
 import type {
@@ -16,6 +17,7 @@ vi.mock("./tauriApi", () => ({
   getAppContext: vi.fn(),
   setScope: vi.fn(),
   setProjectRoot: vi.fn(),
+  inspectProjectRoot: vi.fn(),
   listSkills: vi.fn(),
   listMcpServers: vi.fn(),
   runDotagentsCommand: vi.fn(),
@@ -242,6 +244,58 @@ describe("Dotagents Desktop UI", () => {
     expect(screen.getByText("npx")).toBeInTheDocument();
   });
 
+  it("shows version, author and license from skill frontmatter", async () => {
+    vi.mocked(tauriApi.listSkills).mockResolvedValue([
+      {
+        name: "lint",
+        source: "owner/repo",
+        status: "ok",
+        version: "1.2.0",
+        author: "Jane",
+        license: "MIT",
+      },
+    ]);
+
+    render(<App />);
+
+    expect(
+      await screen.findByText("version 1.2.0 · by Jane · MIT license"),
+    ).toBeInTheDocument();
+  });
+
+  it("shows and filters on derived descriptions", async () => {
+    vi.mocked(tauriApi.listSkills).mockResolvedValue([
+      {
+        name: "changelog",
+        source: "owner/repo",
+        status: "ok",
+        derivedDescription: "Drafts release notes from merged pull requests.",
+      },
+      ...["a", "b", "c", "d", "e"].map((name) => ({
+        name: `skill-${name}`,
+        source: "owner/repo",
+        status: "ok" as const,
+      })),
+    ]);
+
+    const user = userEvent.setup();
+    render(<App />);
+
+    expect(
+      await screen.findByText(
+        "Drafts release notes from merged pull requests.",
+      ),
+    ).toBeInTheDocument();
+
+    await user.type(
+      screen.getByPlaceholderText("Filter by name or description…"),
+      "release notes",
+    );
+
+    expect(screen.getByText("changelog")).toBeInTheDocument();
+    expect(screen.queryByText("skill-a")).not.toBeInTheDocument();
+  });
+
   it("shows sync button as disabled when all skills are ok", async () => {
     render(<App />);
 
@@ -417,6 +471,101 @@ describe("Dotagents Desktop UI", () => {
       screen.getAllByRole("button", { name: "Open ~/.agents" }).length,
     ).toBeGreaterThan(0);
   });
+
+  it("warns when the project folder is inside a cloud-synced folder", async () => {
+    vi.mocked(tauriApi.getAppContext).mockResolvedValue(
+      buildProjectContext({ projectCloudSyncProvider: "Dropbox" }),
+    );
+
+    render(<App />);
+
+    expect(await screen.findByRole("status")).toHaveTextContent(
+      /synced by Dropbox/,
+    );
+  });
+
+  it("warns when the project agents.toml is read-only", async () => {
+    vi.mocked(tauriApi.getAppContext).mockResolvedValue(
+      buildProjectContext({ projectReadOnly: true }),
+    );
+
+    render(<App />);
+
+    expect(await screen.findByRole("status")).toHaveTextContent(
+      /agents\.toml in this project folder is read-only/,
+    );
+  });
+
+  it("previews a picked folder before selecting it", async () => {
+    const user = userEvent.setup();
+    vi.mocked(tauriApi.getAppContext).mockResolvedValue(
+      buildProjectContext({
+        activeProjectContext: { mode: "project", projectRoot: null },
+        projectAgentsTomlPath: null,
+        projectInitialized: null,
+      }),
+    );
+    vi.mocked(openDirectoryDialog).mockResolvedValue("/tmp/picked");
+    vi.mocked(tauriApi.inspectProjectRoot).mockResolvedValue({
+      projectRoot: "/tmp/picked",
+      hasAgentsToml: false,
+      hasAgentsLock: false,
+      hasAgentsDir: true,
+      hasSkillsLock: false,
+      cloudSyncProvider: "iCloud Drive",
+    });
+
+    render(<App />);
+
+    const [chooseFolder] = await screen.findAllByRole("button", {
+      name: "Choose project folder",
+    });
+    await user.click(chooseFolder);
+
+    expect(
+      await screen.findByRole("heading", { name: "Use this folder?" }),
+    ).toBeInTheDocument();
+    expect(tauriApi.inspectProjectRoot).toHaveBeenCalledWith("/tmp/picked");
+    expect(screen.getByText(/No agents.toml here yet/)).toBeInTheDocument();
+    expect(screen.getByText(/synced by iCloud Drive/)).toBeInTheDocument();
+    expect(tauriApi.setProjectRoot).not.toHaveBeenCalled();
+
+    await user.click(screen.getByRole("button", { name: "Use this folder" }));
+
+    await waitFor(() => {
+      expect(tauriApi.setProjectRoot).toHaveBeenCalledWith("/tmp/picked");
+    });
+    expect(
+      screen.queryByRole("heading", { name: "Use this folder?" }),
+    ).not.toBeInTheDocument();
+  });
+
+  it("leaves the project root unchanged when the preview is cancelled", async () => {
+    const user = userEvent.setup();
+    vi.mocked(openDirectoryDialog).mockResolvedValue("/tmp/picked");
+    vi.mocked(tauriApi.inspectProjectRoot).mockResolvedValue({
+      projectRoot: "/tmp/picked",
+      hasAgentsToml: true,
+      hasAgentsLock: true,
+      hasAgentsDir: true,
+      hasSkillsLock: false,
+      readOnly: false,
+    });
+    vi.mocked(tauriApi.getAppContext).mockResolvedValue(buildProjectContext());
+
+    render(<App />);
+
+    await screen.findByText("lint");
+    await user.click(
+      screen.getByRole("button", { name: "Choose project folder" }),
+    );
+    await user.click(await screen.findByRole("button", { name: "Cancel" }));
+
+    expect(
+      screen.queryByRole("heading", { name: "Use this folder?" }),
+    ).not.toBeInTheDocument();
+    expect(tauriApi.setProjectRoot).not.toHaveBeenCalled();
+  });
 });
 
 describe("Workspace switcher", () => {
@@ -436,6 +585,80 @@ describe("Workspace switcher", () => {
     expect(screen.getByText("Active agents")).toBeInTheDocument();
   });
 
+  it("shows where a skill was installed from", async () => {
+    vi.mocked(tauriApi.listSkillsCli).mockResolvedValue([
+      {
+        name: "find-skills",
+        path: "/home/me/.agents/skills/find-skills",
+        scope: "global",
+        agents: ["Claude Code"],
+        source: "vercel-labs/skills",
+        provenance: {
+          sourceType: "github",
+          sourceUrl: "https://github.com/vercel-labs/skills.git",
+          installedAt: "2026-01-02T10:00:00.000Z",
+          updatedAt: "2026-02-03T11:30:00.000Z",
+        },
+      },
+    ]);
+
+    const user = userEvent.setup();
+    render(<App />);
+    await user.click(screen.getByRole("button", { name: "Skills" }));
+
+    expect(
+      await screen.findByText(
+        "github · https://github.com/vercel-labs/skills.git",
+      ),
+    ).toBeInTheDocument();
+    expect(
+      screen.getByText("Installed 2026-01-02 · Updated 2026-02-03"),
+    ).toBeInTheDocument();
+  });
+
+  it("keeps the lock version apart from the declared version", async () => {
+    vi.mocked(tauriApi.listSkillsCli).mockResolvedValue([
+      {
+        name: "adapt",
+        path: "/home/me/.agents/skills/adapt",
+        scope: "global",
+        agents: [],
+        version: "abc123",
+        declaredVersion: "2.1.0",
+        author: "Jane",
+        license: "MIT",
+      },
+    ]);
+
+    const user = userEvent.setup();
+    render(<App />);
+    await user.click(screen.getByRole("button", { name: "Skills" }));
+
+    expect(await screen.findByText("abc123")).toBeInTheDocument();
+    expect(screen.getByText("declared 2.1.0")).toBeInTheDocument();
+    expect(screen.getByText("by Jane · MIT license")).toBeInTheDocument();
+  });
+
+  it("shows a derived description when none is declared", async () => {
+    vi.mocked(tauriApi.listSkillsCli).mockResolvedValue([
+      {
+        name: "adapt",
+        path: "/home/me/.agents/skills/adapt",
+        scope: "global",
+        agents: [],
+        derivedDescription: "Adapts layouts to each screen size.",
+      },
+    ]);
+
+    const user = userEvent.setup();
+    render(<App />);
+    await user.click(screen.getByRole("button", { name: "Skills" }));
+
+    expect(
+      await screen.findByText("Adapts layouts to each screen size."),
+    ).toBeInTheDocument();
+  });
+
   it("preserves workspace selection across remounts via localStorage", async () => {
     const user = userEvent.setup();
     const first = render(<App />);
@@ -0,0 +1,15 @@
+type SkillDetails = {
+  version?: string | null;
+  author?: string | null;
+  license?: string | null;
+};
+
+// One muted line of frontmatter details, or null when the skill declares none.
+export function skillDetailsLine(details: SkillDetails): string | null {
+  const parts = [
+    details.version ? `version ${details.version}` : null,
+    details.author ? `by ${details.author}` : null,
+    details.license ? `${details.license} license` : null,
+  ].filter(Boolean);
+  return parts.length > 0 ? parts.join(" · ") : null;
+}
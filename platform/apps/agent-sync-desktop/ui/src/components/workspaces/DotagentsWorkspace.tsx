@@ -12,9 +12,11 @@ import { cn, commandFailureMessage, errorMessage } from "../../lib/utils";
 import { OutputPanel } from "../shared/OutputPanel";
 import { toneClass } from "../shared/tone";
 import { RemovalActions } from "../shared/RemovalActions";
+import { skillDetailsLine } from "../shared/skillDetails";
 import {
   getAppContext,
   getRuntimeStatus,
+  inspectProjectRoot,
   listMcpServers,
   listSkills,
   openAgentsDir,
@@ -32,6 +34,7 @@ import type {
   DotagentsScope,
   DotagentsSkillListItem,
   DotagentsSkillStatus,
+  ProjectRootInspection,
 } from "../../types";
 
 const DOCS_URL = "https://dotagents.sentry.dev/cli";
@@ -41,6 +44,8 @@ const EXAMPLE_CONFIG = `version = 1
 agents = ["claude", "codex"]
 skills = []
 mcp = []`;
+const READ_ONLY_PROJECT_MESSAGE =
+  "agents.toml in this project folder is read-only, so every dotagents command here will be refused until it is writable again.";
 
 function isReadyContext(context: AppContext | null): boolean {
   if (!context) {
@@ -164,6 +169,92 @@ function SectionActions({
   );
 }
 
+function FolderPreview({
+  inspection,
+  busyAction,
+  onConfirm,
+  onCancel,
+}: {
+  inspection: ProjectRootInspection;
+  busyAction: string | null;
+  onConfirm: () => Promise<void>;
+  onCancel: () => void;
+}) {
+  const found = [
+    { label: "agents.toml", present: inspection.hasAgentsToml },
+    { label: "agents.lock", present: inspection.hasAgentsLock },
+    { label: ".agents/", present: inspection.hasAgentsDir },
+    { label: "skills-lock.json", present: inspection.hasSkillsLock },
+  ];
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>Use this folder?</CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-3 text-sm">
+        <code className="block truncate rounded bg-muted/40 px-2 py-1 font-mono text-[13px] text-foreground">
+          {inspection.projectRoot}
+        </code>
+        <ul className="flex flex-wrap gap-1.5">
+          {found.map(({ label, present }) => (
+            <li
+              key={label}
+              className={cn(
+                "rounded-sm border px-2 py-0.5 text-xs",
+                present
+                  ? toneClass("neutral")
+                  : "border-border/50 text-muted-foreground line-through",
+              )}
+            >
+              {label}
+            </li>
+          ))}
+        </ul>
+        {!inspection.hasAgentsToml ? (
+          <p className="text-muted-foreground">
+            No agents.toml here yet. The folder will need to be initialized
+            before skills and MCP servers can be managed.
+          </p>
+        ) : null}
+        {inspection.readOnly ? (
+          <p
+            className={cn(
+              "rounded-sm border px-2 py-1.5",
+              toneClass("warning"),
+            )}
+          >
+            {READ_ONLY_PROJECT_MESSAGE}
+          </p>
+        ) : null}
+        {inspection.cloudSyncProvider ? (
+          <p
+            className={cn(
+              "rounded-sm border px-2 py-1.5",
+              toneClass("warning"),
+            )}
+          >
+            This folder is synced by {inspection.cloudSyncProvider}, which is
+            known to break the symlinks dotagents creates.
+          </p>
+        ) : null}
+        <div className="flex flex-wrap gap-2">
+          <Button
+            size="sm"
+            onClick={() => void onConfirm()}
+            disabled={busyAction !== null}
+          >
+            Use this folder
+          </Button>
+          <Button size="sm" variant="outline" onClick={onCancel}>
+            Cancel
+          </Button>
+        </div>
+      </CardContent>
+    </Card>
+  );
+}
+
 type DotagentsWorkspaceProps = {
   onReady?: () => void;
 };
@@ -181,6 +272,8 @@ export function DotagentsWorkspace({ onReady }: DotagentsWorkspaceProps = {}) {
   const [isLoading, setIsLoading] = useState(true);
   const [busyAction, setBusyAction] = useState<string | null>(null);
   const [error, setError] = useState<string | null>(null);
+  const [folderPreview, setFolderPreview] =
+    useState<ProjectRootInspection | null>(null);
   const [pendingRemoval, setPendingRemoval] = useState<{
     kind: "skill" | "mcp";
     name: string;
@@ -196,7 +289,9 @@ export function DotagentsWorkspace({ onReady }: DotagentsWorkspaceProps = {}) {
     ? skills.filter(
         (s) =>
           s.name.toLowerCase().includes(skillFilter.toLowerCase()) ||
-          s.description?.toLowerCase().includes(skillFilter.toLowerCase()),
+          (s.description ?? s.derivedDescription)
+            ?.toLowerCase()
+            .includes(skillFilter.toLowerCase()),
       )
     : skills;
 
@@ -274,7 +369,17 @@ export function DotagentsWorkspace({ onReady }: DotagentsWorkspaceProps = {}) {
       if (!pickedPath) {
         return;
       }
-      await setProjectRoot(pickedPath);
+      setFolderPreview(await inspectProjectRoot(pickedPath));
+    });
+  }
+
+  async function handleConfirmProjectRoot() {
+    if (!folderPreview) {
+      return;
+    }
+    await runBusyAction("projectRoot", async () => {
+      await setProjectRoot(folderPreview.projectRoot);
+      setFolderPreview(null);
       await refreshApp();
     });
   }
@@ -523,6 +628,7 @@ export function DotagentsWorkspace({ onReady }: DotagentsWorkspaceProps = {}) {
                   const isRemoving =
                     pendingRemoval?.kind === "skill" &&
                     pendingRemoval.name === skill.name;
+                  const details = skillDetailsLine(skill);
                   return (
                     <div
                       key={`${skill.name}:${skill.source}`}
@@ -558,6 +664,16 @@ export function DotagentsWorkspace({ onReady }: DotagentsWorkspaceProps = {}) {
                         <p className="line-clamp-2 text-[13px] leading-relaxed text-muted-foreground">
                           {skill.description}
                         </p>
+                      ) : skill.derivedDescription ? (
+                        <p className="line-clamp-2 text-[13px] italic leading-relaxed text-muted-foreground/80">
+                          {skill.derivedDescription}
+                        </p>
+                      ) : null}
+
+                      {details ? (
+                        <div className="text-xs text-muted-foreground">
+                          {details}
+                        </div>
                       ) : null}
 
                       {hint ? (
@@ -818,12 +934,48 @@ export function DotagentsWorkspace({ onReady }: DotagentsWorkspaceProps = {}) {
         </div>
       </div>
 
+      {currentScope === "project" && appContext?.projectCloudSyncProvider ? (
+        <div
+          role="status"
+          className={cn(
+            "rounded-md border px-3 py-2 text-sm",
+            toneClass("warning"),
+          )}
+        >
+          This project folder is synced by{" "}
+          {appContext.projectCloudSyncProvider}, which is known to break the
+          symlinks dotagents creates. Move the project out of the synced folder
+          if installed skills go missing.
+        </div>
+      ) : null}
+
+      {currentScope === "project" && appContext?.projectReadOnly ? (
+        <div
+          role="status"
+          className={cn(
+            "rounded-md border px-3 py-2 text-sm",
+            toneClass("warning"),
+          )}
+        >
+          {READ_ONLY_PROJECT_MESSAGE}
+        </div>
+      ) : null}
+
       {error ? (
         <div className="rounded-md border border-destructive/30 bg-destructive/10 px-3 py-2 text-sm text-destructive">
           {error}
         </div>
       ) : null}
 
+      {folderPreview ? (
+        <FolderPreview
+          inspection={folderPreview}
+          busyAction={busyAction}
+          onConfirm={handleConfirmProjectRoot}
+          onCancel={() => setFolderPreview(null)}
+        />
+      ) : null}
+
       {isLoading ? (
         <Card>
           <CardContent className="p-4 text-sm text-muted-foreground">
@@ -4,6 +4,7 @@ import { Card, CardContent } from "../ui/card";
 import { cn, commandFailureMessage, errorMessage } from "../../lib/utils";
 import { OutputPanel } from "../shared/OutputPanel";
 import { RemovalActions } from "../shared/RemovalActions";
+import { skillDetailsLine } from "../shared/skillDetails";
 import {
   getSkillsWorkspaceContext,
   listSkillsCli,
@@ -14,6 +15,7 @@ import {
 import type {
   SkillsCliCommandResult,
   SkillsCliListItem,
+  SkillsCliProvenance,
   SkillsCliScope,
   SkillsWorkspaceContext,
 } from "../../types";
@@ -41,6 +43,24 @@ function uniqueAgents(values: readonly string[]): string[] {
   return result;
 }
 
+// skills-lock.json stores ISO timestamps; the calendar day is enough here.
+function provenanceDetails(provenance: SkillsCliProvenance): string[] {
+  const origin = [provenance.sourceType, provenance.sourceUrl]
+    .filter(Boolean)
+    .join(" · ");
+  const dates = [
+    provenance.installedAt
+      ? `Installed ${provenance.installedAt.slice(0, 10)}`
+      : null,
+    provenance.updatedAt
+      ? `Updated ${provenance.updatedAt.slice(0, 10)}`
+      : null,
+  ]
+    .filter(Boolean)
+    .join(" · ");
+  return [origin, dates].filter(Boolean);
+}
+
 type SkillsWorkspaceProps = {
   onReady?: () => void;
 };
@@ -364,6 +384,10 @@ export function SkillsWorkspace({ onReady }: SkillsWorkspaceProps = {}) {
           <div className="grid gap-3">
             {skills.map((skill) => {
               const isRemoving = pendingRemoval === skill.name;
+              const details = skillDetailsLine({
+                author: skill.author,
+                license: skill.license,
+              });
               return (
                 <div
                   key={`${skill.scope}:${skill.name}`}
@@ -387,6 +411,16 @@ export function SkillsWorkspace({ onReady }: SkillsWorkspaceProps = {}) {
                           <span className="text-muted-foreground/70">—</span>
                         )}
                       </div>
+                      {skill.provenance
+                        ? provenanceDetails(skill.provenance).map((line) => (
+                            <div
+                              key={line}
+                              className="truncate text-xs text-muted-foreground"
+                            >
+                              {line}
+                            </div>
+                          ))
+                        : null}
                     </div>
                     <div className="min-w-0">
                       <div className="text-xs font-medium uppercase tracking-wide text-muted-foreground/70">
@@ -426,6 +460,11 @@ export function SkillsWorkspace({ onReady }: SkillsWorkspaceProps = {}) {
                           <span className="text-muted-foreground/70">—</span>
                         )}
                       </div>
+                      {skill.declaredVersion ? (
+                        <div className="text-xs text-muted-foreground">
+                          declared {skill.declaredVersion}
+                        </div>
+                      ) : null}
                     </div>
                   </div>
 
@@ -433,6 +472,16 @@ export function SkillsWorkspace({ onReady }: SkillsWorkspaceProps = {}) {
                     <p className="text-sm text-muted-foreground">
                       {skill.description}
                     </p>
+                  ) : skill.derivedDescription ? (
+                    <p className="text-sm italic text-muted-foreground/80">
+                      {skill.derivedDescription}
+                    </p>
+                  ) : null}
+
+                  {details ? (
+                    <div className="text-xs text-muted-foreground">
+                      {details}
+                    </div>
                   ) : null}
 
                   <div className="flex flex-wrap gap-2">
@@ -4,6 +4,7 @@ import {
   getAppContext,
   getRuntimeStatus,
   getSkillsWorkspaceContext,
+  inspectProjectRoot,
   listMcpServers,
   listSkills,
   listSkillsCli,
@@ -49,6 +50,14 @@ describe("tauriApi payloads", () => {
     });
   });
 
+  it("inspects a project root without selecting it", async () => {
+    await inspectProjectRoot("/tmp/demo");
+
+    expect(invoke).toHaveBeenCalledWith("inspect_project_root", {
+      projectRoot: "/tmp/demo",
+    });
+  });
+
   it("lists vendor reads without payloads", async () => {
     await listSkills();
     await listMcpServers();
@@ -7,6 +7,7 @@ import type {
   DotagentsRuntimeStatus,
   DotagentsScope,
   DotagentsSkillListItem,
+  ProjectRootInspection,
   SkillsCliCommandRequest,
   SkillsCliCommandResult,
   SkillsCliListItem,
@@ -32,6 +33,12 @@ export async function setProjectRoot(
   return invoke<AppContext>("set_project_root", { projectRoot });
 }
 
+export async function inspectProjectRoot(
+  projectRoot: string,
+): Promise<ProjectRootInspection> {
+  return invoke<ProjectRootInspection>("inspect_project_root", { projectRoot });
+}
+
 export async function listSkills(): Promise<DotagentsSkillListItem[]> {
   return invoke<DotagentsSkillListItem[]>("list_skills");
 }
@@ -7,8 +7,12 @@ export type DotagentsSkillListItem = {
   source: string;
   status: DotagentsSkillStatus;
   description?: string | null;
+  derivedDescription?: string | null;
   commit?: string | null;
   wildcard?: string | null;
+  version?: string | null;
+  author?: string | null;
+  license?: string | null;
 };
 
 export type DotagentsMcpTransport = "stdio" | "http";
@@ -51,6 +55,18 @@ export type AppContext = {
   userInitialized: boolean;
   projectAgentsTomlPath?: string | null;
   projectInitialized?: boolean | null;
+  projectReadOnly?: boolean | null;
+  projectCloudSyncProvider?: string | null;
+};
+
+export type ProjectRootInspection = {
+  projectRoot: string;
+  hasAgentsToml: boolean;
+  hasAgentsLock: boolean;
+  hasAgentsDir: boolean;
+  hasSkillsLock: boolean;
+  readOnly?: boolean | null;
+  cloudSyncProvider?: string | null;
 };
 
 export type DotagentsCommandRequest =
@@ -81,6 +97,13 @@ export type DotagentsCommandRequest =
 
 export type SkillsCliScope = "global" | "project";
 
+export type SkillsCliProvenance = {
+  sourceType?: string | null;
+  sourceUrl?: string | null;
+  installedAt?: string | null;
+  updatedAt?: string | null;
+};
+
 export type SkillsCliListItem = {
   name: string;
   path: string;
@@ -88,7 +111,12 @@ export type SkillsCliListItem = {
   agents: string[];
   source?: string | null;
   version?: string | null;
+  declaredVersion?: string | null;
   description?: string | null;
+  derivedDescription?: string | null;
+  author?: string | null;
+  license?: string | null;
+  provenance?: SkillsCliProvenance | null;
 };
 
 export type SkillsCliCommandRequest =